@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{build_span, coalesce_spans, is_animated, time_scale, ShimmerConfig, ShimmerState};
+
+/// A shimmer whose cells fade out behind the band instead of snapping back to the base style.
+///
+/// Every [`tick`](AfterglowShimmer::tick) keeps, per cell, the larger of the band intensity and
+/// the previous intensity decayed by the [`half_life`](AfterglowShimmer::new), leaving a trail
+/// behind the sweep. The trail is cleared whenever [`set_text`](AfterglowShimmer::set_text)
+/// changes the text.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::AfterglowShimmer;
+///
+/// let mut shimmer = AfterglowShimmer::new(Duration::from_millis(150)).sweep_seconds(2.0);
+/// shimmer.set_text("Loading...");
+///
+/// // Once per frame, until the band has moved on past the first cell.
+/// for _ in 0..75 {
+///     shimmer.tick(Duration::from_millis(16));
+/// }
+/// let spans = shimmer.spans(Style::default());
+///
+/// // Behind the band the trail halves every half-life, decaying toward zero.
+/// let trail = shimmer.intensities()[0];
+/// assert!(trail > 0.0);
+/// shimmer.tick(Duration::from_millis(150));
+/// assert!((shimmer.intensities()[0] - trail / 2.0).abs() < 1e-4);
+/// shimmer.tick(Duration::from_millis(300));
+/// assert!((shimmer.intensities()[0] - trail / 8.0).abs() < 1e-4);
+///
+/// // Setting the same text keeps the trail; new text clears it.
+/// shimmer.set_text("Loading...");
+/// assert!(shimmer.intensities()[0] > 0.0);
+/// shimmer.set_text("Saving...");
+/// assert_eq!(shimmer.intensities(), [0.0; 9]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AfterglowShimmer {
+    state: ShimmerState,
+    half_life: Duration,
+    config: ShimmerConfig,
+    text: String,
+    glow: Vec<f32>,
+}
+
+impl AfterglowShimmer {
+    /// Creates an afterglow shimmer with no text whose trail halves every `half_life`.
+    pub fn new(half_life: Duration) -> Self {
+        Self {
+            state: ShimmerState::new(),
+            half_life,
+            config: ShimmerConfig::default(),
+            text: String::new(),
+            glow: Vec::new(),
+        }
+    }
+
+    /// Sets the duration of one full sweep in seconds. Defaults to 2 seconds.
+    #[must_use]
+    pub fn sweep_seconds(mut self, sweep_seconds: f32) -> Self {
+        self.state = self.state.sweep_seconds(sweep_seconds);
+        self
+    }
+
+    /// Sets the shimmer configuration used for the band and cell styles.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Replaces the text, clearing the trail if it differs from the current text.
+    pub fn set_text(&mut self, text: &str) {
+        if self.text != text {
+            self.text = text.to_owned();
+            self.glow = vec![0.0; text.chars().count()];
+        }
+    }
+
+    /// The text currently being rendered.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Advances the band by `dt`, scaled by the process-wide [`time_scale`], and decays the trail.
+    pub fn tick(&mut self, dt: Duration) {
+        self.state.tick(dt);
+        let decay = if self.half_life.is_zero() {
+            0.0
+        } else {
+            let elapsed = dt.mul_f32(time_scale()).as_secs_f32();
+            0.5_f32.powf(elapsed / self.half_life.as_secs_f32())
+        };
+        let band = Band::new(self.glow.len(), self.state.phase(), &self.config);
+        for (index, glow) in self.glow.iter_mut().enumerate() {
+            *glow = band.intensity(index).max(*glow * decay);
+        }
+    }
+
+    /// Current per-character intensity (0.0..=1.0), including the trail.
+    pub fn intensities(&self) -> &[f32] {
+        &self.glow
+    }
+
+    /// Renders the text at the current intensities.
+    pub fn spans(&self, base_style: Style) -> Vec<Span<'static>> {
+        if self.text.is_empty() {
+            return Vec::new();
+        }
+        if !is_animated(&self.text, &self.config) {
+            return vec![build_span(self.text.as_str(), base_style)];
+        }
+
+        let painter = CellPainter::new(base_style, &self.config);
+        let cells = self
+            .text
+            .chars()
+            .zip(&self.glow)
+            .enumerate()
+            .map(|(index, (ch, &glow))| (ch, painter.style(index, glow)));
+        coalesce_spans(cells, self.glow.len())
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::Span;
+
+use crate::{shimmer_display_width, shimmer_frames, ShimmerConfig};
+
+/// SGR parameters for each modifier, in the order they are emitted.
+const MODIFIER_CODES: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+/// Writes `duration` of the shimmer on `text` as an asciicast v2 recording.
+///
+/// The output is a JSON header with the terminal size and start time, followed by one output
+/// event per frame at `fps` frames per second, rendered as by [`shimmer_frames`]. Each frame
+/// is written as ANSI escapes on a single row, returning to the start of the row before the
+/// next one, so the file plays in `asciinema play` and the asciinema web player.
+/// A non-positive or non-finite `fps` is rejected with [`io::ErrorKind::InvalidInput`].
+///
+/// Requires the `asciicast` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{export_asciicast, ShimmerConfig};
+///
+/// let mut cast = Vec::new();
+/// export_asciicast(
+///     "Loading...",
+///     Style::default(),
+///     &ShimmerConfig::default(),
+///     Duration::from_secs(1),
+///     10.0,
+///     &mut cast,
+/// )?;
+///
+/// let cast = String::from_utf8(cast).unwrap();
+/// let mut lines = cast.lines();
+/// let header = lines.next().unwrap();
+/// assert!(header.starts_with(r#"{"version": 2, "width": 10, "height": 1, "timestamp": "#));
+///
+/// let times: Vec<f64> = lines
+///     .map(|event| {
+///         let (time, rest) = event.strip_prefix('[').unwrap().split_once(',').unwrap();
+///         assert!(rest.starts_with(r#" "o", ""#));
+///         time.parse().unwrap()
+///     })
+///     .collect();
+/// assert_eq!(times.len(), 10);
+/// assert!(times.windows(2).all(|pair| pair[0] < pair[1]));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn export_asciicast(
+    text: &str,
+    base_style: Style,
+    config: &ShimmerConfig,
+    duration: Duration,
+    fps: f32,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    if !(fps > 0.0 && fps.is_finite()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "frames per second must be positive and finite",
+        ));
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    writeln!(
+        writer,
+        r#"{{"version": 2, "width": {}, "height": 1, "timestamp": {timestamp}}}"#,
+        shimmer_display_width(text).max(1),
+    )?;
+
+    let frame_count = (duration.as_secs_f64() * f64::from(fps)).ceil() as usize;
+    let frames = shimmer_frames(text, base_style, config, fps).take(frame_count);
+    let mut output = String::new();
+    for (index, frame) in frames.enumerate() {
+        output.clear();
+        if index > 0 {
+            output.push('\r');
+        }
+        write_ansi(&mut output, &frame);
+        write!(writer, "[{:.6}, \"o\", ", index as f64 / f64::from(fps))?;
+        write_json_string(&mut writer, &output)?;
+        writeln!(writer, "]")?;
+    }
+    writer.flush()
+}
+
+/// Appends `spans` as text with SGR escapes, ending with a reset.
+fn write_ansi(output: &mut String, spans: &[Span<'_>]) {
+    for span in spans {
+        output.push_str("\x1b[0");
+        push_sgr(output, span.style);
+        output.push('m');
+        output.push_str(&span.content);
+    }
+    output.push_str("\x1b[0m");
+}
+
+/// Appends the `;`-prefixed SGR parameters for `style` on top of a reset.
+fn push_sgr(output: &mut String, style: Style) {
+    for (modifier, code) in MODIFIER_CODES {
+        if style.add_modifier.contains(modifier) {
+            let _ = write!(output, ";{code}");
+        }
+    }
+    if let Some(fg) = style.fg {
+        push_color(output, fg, 30);
+    }
+    if let Some(bg) = style.bg {
+        push_color(output, bg, 40);
+    }
+}
+
+/// Appends `color` as a foreground (`base` 30) or background (`base` 40) parameter.
+fn push_color(output: &mut String, color: Color, base: u8) {
+    let _ = match color {
+        Color::Reset => write!(output, ";{}", base + 9),
+        Color::Black => write!(output, ";{base}"),
+        Color::Red => write!(output, ";{}", base + 1),
+        Color::Green => write!(output, ";{}", base + 2),
+        Color::Yellow => write!(output, ";{}", base + 3),
+        Color::Blue => write!(output, ";{}", base + 4),
+        Color::Magenta => write!(output, ";{}", base + 5),
+        Color::Cyan => write!(output, ";{}", base + 6),
+        Color::Gray => write!(output, ";{}", base + 7),
+        Color::DarkGray => write!(output, ";{}", base + 60),
+        Color::LightRed => write!(output, ";{}", base + 61),
+        Color::LightGreen => write!(output, ";{}", base + 62),
+        Color::LightYellow => write!(output, ";{}", base + 63),
+        Color::LightBlue => write!(output, ";{}", base + 64),
+        Color::LightMagenta => write!(output, ";{}", base + 65),
+        Color::LightCyan => write!(output, ";{}", base + 66),
+        Color::White => write!(output, ";{}", base + 67),
+        Color::Indexed(code) => write!(output, ";{};5;{code}", base + 8),
+        Color::Rgb(r, g, b) => write!(output, ";{};2;{r};{g};{b}", base + 8),
+    };
+}
+
+/// Writes `value` as a quoted JSON string.
+fn write_json_string(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for ch in value.chars() {
+        match ch {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            '\n' => write!(writer, "\\n")?,
+            '\r' => write!(writer, "\\r")?,
+            '\t' => write!(writer, "\\t")?,
+            ch if u32::from(ch) < 0x20 || ch == '\u{7f}' => {
+                write!(writer, "\\u{:04x}", u32::from(ch))?;
+            }
+            ch => write!(writer, "{ch}")?,
+        }
+    }
+    write!(writer, "\"")
+}
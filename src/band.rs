@@ -0,0 +1,427 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::{char_width, moves_rtl, ShimmerConfig, BAND_HALF_WIDTH};
+
+/// Bounds on [`ShimmerConfig::lut_resolution`].
+const DENSE_LUT_RESOLUTIONS: (usize, usize) = (2, 1024);
+const DENSE_LUT_SLOTS: usize = DENSE_LUT_RESOLUTIONS.1 - DENSE_LUT_RESOLUTIONS.0 + 1;
+
+static INTENSITY_LUT: OnceLock<Vec<f32>> = OnceLock::new();
+/// Dense falloff tables for [`ShimmerConfig::lut_resolution`], one slot per resolution and
+/// built the first time that resolution is used.
+static DENSE_LUTS: [OnceLock<Vec<f32>>; DENSE_LUT_SLOTS] =
+    [const { OnceLock::new() }; DENSE_LUT_SLOTS];
+
+/// Position of the shimmer band relative to the start of the text.
+///
+/// Columns are display columns from the first text cell: a wide glyph spans two, and each
+/// padding cell one. A negative column or one past the text width lies in the padding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandGeometry {
+    /// Column of the band center, the brightest cell. With [`ShimmerConfig::sub_cell`] it
+    /// moves smoothly between cells instead of snapping to whole columns.
+    pub peak_col: f32,
+    /// First column touched by the band.
+    pub start_col: f32,
+    /// Last column touched by the band.
+    pub end_col: f32,
+    /// Whether any text cell is currently inside the band.
+    pub on_text: bool,
+}
+
+/// Returns where the band sits over `text` at `phase`.
+///
+/// The geometry uses the same band position as the span builders, so `peak_col` is the first
+/// column of the brightest cell of a render with the same `config`. Use it to keep companion
+/// UI, such as a marker on the row above, in sync with the animation.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{band_geometry, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let geometry = band_geometry("Loading...", 0.5, &config);
+/// assert_eq!(geometry.peak_col, 5.0);
+/// assert!(geometry.on_text);
+///
+/// // Three wide glyphs put the fourth character, the space, at column 6.
+/// let geometry = band_geometry("下载中 data", 13.5 / 28.0, &config);
+/// assert_eq!(geometry.peak_col, 6.0);
+/// assert_eq!((geometry.start_col, geometry.end_col), (-2.0, 11.0));
+///
+/// // With sub-cell rendering the peak sits between cells for in-between phases.
+/// let smooth = ShimmerConfig { sub_cell: true, ..config.clone() };
+/// let peak = band_geometry("Loading...", 15.25 / 30.0, &smooth).peak_col;
+/// assert!((peak - 5.25).abs() < 1e-4);
+/// assert_eq!(band_geometry("Loading...", 15.25 / 30.0, &config).peak_col, 5.0);
+/// ```
+///
+/// Across a sweep, `peak_col` is the column of the brightest rendered cell whenever the
+/// center is over the text, and no cell is lit while `on_text` is false:
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{band_geometry, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let text = "下载中 data";
+/// let columns = [0.0, 2.0, 4.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+/// let base = Style::default().fg(Color::Rgb(0, 0, 0));
+/// let resting = shimmer_styles(text, base, 0.0, &config)[0];
+/// for step in 0..28 {
+///     let phase = step as f32 / 28.0;
+///     let geometry = band_geometry(text, phase, &config);
+///     let styles = shimmer_styles(text, base, phase, &config);
+///     let level = |index: usize| match styles[index].fg {
+///         Some(Color::Rgb(level, ..)) => level,
+///         _ => unreachable!(),
+///     };
+///     let brightest = (0..styles.len()).max_by_key(|&index| level(index)).unwrap();
+///     if columns.contains(&geometry.peak_col) {
+///         assert_eq!(columns[brightest], geometry.peak_col);
+///     }
+///     if !geometry.on_text {
+///         assert!(styles.iter().all(|style| *style == resting));
+///     }
+/// }
+/// assert!(!band_geometry(text, 0.0, &config).on_text);
+/// assert!(!band_geometry(text, 27.0 / 28.0, &config).on_text);
+/// ```
+pub fn band_geometry(text: &str, phase: f32, config: &ShimmerConfig) -> BandGeometry {
+    let widths: Vec<usize> = text.chars().map(char_width).collect();
+    let band = Band::new(widths.len(), phase, config);
+    let peak = band.center() as f32 + band.fraction;
+    let column = |index: f32| cell_to_column(&widths, index);
+    BandGeometry {
+        peak_col: column(peak),
+        start_col: column(peak - band.left as f32),
+        end_col: column(peak + band.right as f32 + 1.0) - 1.0,
+        on_text: band.touches_text(widths.len()),
+    }
+}
+
+/// Display column where the cell at `index` starts, interpolating across a wide glyph for a
+/// fractional index. Padding cells before and after the text are one column each.
+fn cell_to_column(widths: &[usize], index: f32) -> f32 {
+    if index < 0.0 {
+        return index;
+    }
+    let cell = index.floor() as usize;
+    let before: usize = widths.iter().take(cell).sum();
+    match widths.get(cell) {
+        Some(&width) => before as f32 + (index - cell as f32) * width as f32,
+        None => before as f32 + (index - widths.len() as f32),
+    }
+}
+
+/// Returns the longest redraw interval at which the band still moves at most one cell per frame.
+///
+/// The band crosses `char_count + 2 * padding` cells every `sweep_seconds` (counting only the
+/// [`active_range`](ShimmerConfig::active_range) when set), so redrawing more
+/// often than this only repeats frames. Pass the sweep length your clock uses:
+/// `2.0` for [`shimmer_spans_with_style`](crate::shimmer_spans_with_style), or the value given
+/// to [`ShimmerState::sweep_seconds`](crate::ShimmerState::sweep_seconds). A sweep that does
+/// not move returns [`Duration::MAX`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use tui_shimmer::{recommended_min_frame_interval, ShimmerConfig};
+///
+/// // 20 characters plus 10 cells of padding on each side, swept in 2 seconds.
+/// let interval = recommended_min_frame_interval(20, 2.0, &ShimmerConfig::default());
+/// assert_eq!(interval, Duration::from_millis(50));
+/// ```
+pub fn recommended_min_frame_interval(
+    char_count: usize,
+    sweep_seconds: f32,
+    config: &ShimmerConfig,
+) -> Duration {
+    let period = band_period(char_count, config);
+    if period == 0 || !(sweep_seconds > 0.0 && sweep_seconds.is_finite()) {
+        return Duration::MAX;
+    }
+    Duration::from_secs_f64(f64::from(sweep_seconds) / period as f64)
+}
+
+/// Returns how many frames one full sweep lasts at `fps` frames per second.
+///
+/// This is `round(fps * sweep_seconds)`, the loop length to pre-render or record before the
+/// animation repeats, and at least 1 when both inputs are positive. A zero `fps`, or a
+/// non-positive or non-finite `sweep_seconds`, returns 0 since the band does not loop.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::frames_per_cycle;
+///
+/// assert_eq!(frames_per_cycle(60, 2.0), 120);
+/// assert_eq!(frames_per_cycle(30, 1.25), 38);
+/// assert_eq!(frames_per_cycle(0, 2.0), 0);
+/// assert_eq!(frames_per_cycle(60, -1.0), 0);
+/// assert_eq!(frames_per_cycle(60, f32::NAN), 0);
+/// ```
+pub fn frames_per_cycle(fps: u32, sweep_seconds: f32) -> u32 {
+    if fps == 0 || !(sweep_seconds > 0.0 && sweep_seconds.is_finite()) {
+        return 0;
+    }
+    (f64::from(fps) * f64::from(sweep_seconds))
+        .round()
+        .clamp(1.0, f64::from(u32::MAX)) as u32
+}
+
+/// Band position for a single frame, shared by every per-cell query.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Band {
+    pos: isize,
+    /// Sub-cell part of the band position, always 0.0 unless [`ShimmerConfig::sub_cell`] is set.
+    fraction: f32,
+    padding: usize,
+    /// Half-width toward the end of the text: the leading width, or the trailing one when the
+    /// band moves right to left.
+    right: usize,
+    /// Half-width toward the start of the text.
+    left: usize,
+    char_count: usize,
+    /// Char indices the band travels over and lights, from [`ShimmerConfig::active_range`].
+    active: (usize, usize),
+    center_emphasis: f32,
+    /// Intensity below which cells count as outside the band.
+    dead_zone: f32,
+    /// Falloff sampled over one half-width, for the sub-cell path.
+    dense_lut: Option<&'static [f32]>,
+}
+
+impl Band {
+    pub(crate) fn new(char_count: usize, phase: f32, config: &ShimmerConfig) -> Self {
+        let phase = phase.rem_euclid(1.0);
+        let active = active_cells(char_count, config);
+        let period = band_period(char_count, config);
+        let exact = phase * period as f32;
+        let pos = exact as isize;
+        let leading = config
+            .leading_width
+            .map_or(config.band_half_width, |width| width.min(config.padding));
+        let trailing = config
+            .trailing_width
+            .map_or(config.band_half_width, |width| width.min(config.padding));
+        let (left, right) = if moves_rtl(config) {
+            (leading, trailing)
+        } else {
+            (trailing, leading)
+        };
+        Self {
+            pos,
+            fraction: if config.sub_cell {
+                exact - pos as f32
+            } else {
+                0.0
+            },
+            padding: config.padding,
+            right,
+            left,
+            char_count,
+            active,
+            center_emphasis: config.center_emphasis.clamp(0.0, 1.0),
+            dead_zone: config.dead_zone,
+            dense_lut: config
+                .lut_resolution
+                .filter(|_| config.sub_cell)
+                .map(dense_lut),
+        }
+    }
+
+    pub(crate) fn intensity(&self, index: usize) -> f32 {
+        if !self.is_active(index) {
+            return 0.0;
+        }
+        let intensity = self.falloff(self.cells_from_pos(index));
+        let intensity = if self.center_emphasis > 0.0 && self.char_count > 1 {
+            let mid = (self.char_count - 1) as f32 / 2.0;
+            let from_mid = (index as f32 - mid).abs() / mid;
+            intensity * (1.0 - self.center_emphasis * from_mid.min(1.0))
+        } else {
+            intensity
+        };
+        self.cut_dead_zone(intensity)
+    }
+
+    /// Intensity of a band centered `offset` cells behind this one, at the cell at `index`.
+    pub(crate) fn intensity_offset(&self, index: usize, offset: isize) -> f32 {
+        if !self.is_active(index) {
+            return 0.0;
+        }
+        self.cut_dead_zone(self.falloff(self.cells_from_pos(index) + offset))
+    }
+
+    fn cut_dead_zone(&self, intensity: f32) -> f32 {
+        if intensity < self.dead_zone {
+            0.0
+        } else {
+            intensity
+        }
+    }
+
+    fn is_active(&self, index: usize) -> bool {
+        (self.active.0..self.active.1).contains(&index)
+    }
+
+    /// Cells from the whole-cell band position to the cell at `index`.
+    fn cells_from_pos(&self, index: usize) -> isize {
+        index as isize - self.active.0 as isize + self.padding as isize - self.pos
+    }
+
+    /// Intensity of the cell `cells` positions after the whole-cell band position.
+    ///
+    /// Cells after the center fall off over the right half-width, cells before it over the
+    /// left one.
+    fn falloff(&self, cells: isize) -> f32 {
+        if self.fraction != 0.0 {
+            let signed = cells as f32 - self.fraction;
+            let half_width = if signed > 0.0 { self.right } else { self.left };
+            return match self.dense_lut {
+                Some(lut) if half_width > 0 => sampled_falloff(lut, signed.abs(), half_width),
+                _ => smooth_falloff(signed.abs(), half_width),
+            };
+        }
+        let half_width = if cells > 0 { self.right } else { self.left };
+        let dist = cells.unsigned_abs();
+        if dist > half_width {
+            0.0
+        } else if half_width == BAND_HALF_WIDTH {
+            intensity_lut()[dist]
+        } else {
+            cosine_falloff(dist, half_width)
+        }
+    }
+
+    /// Text cell at the band's leading edge: the outermost lit cell on the side it moves
+    /// toward, or `None` when that edge lies outside the text.
+    pub(crate) fn leading_edge(&self, rtl: bool) -> Option<usize> {
+        let (start, end) = self.active;
+        let lit = |index: &usize| self.intensity(*index) > 0.0;
+        let (edge, beyond) = if rtl {
+            let edge = (start..end).find(lit)?;
+            (edge, self.cells_from_pos(edge) - 1)
+        } else {
+            let edge = (start..end).rev().find(lit)?;
+            (edge, self.cells_from_pos(edge) + 1)
+        };
+        // The band still lights the cell past the edge, so its real edge is off the text.
+        (self.cut_dead_zone(self.falloff(beyond)) <= 0.0).then_some(edge)
+    }
+
+    /// Whole-cell band position and its sub-cell part; equal positions render identically.
+    pub(crate) fn position(&self) -> (isize, f32) {
+        (self.pos, self.fraction)
+    }
+
+    /// Band center as a column relative to the first text cell.
+    pub(crate) fn center(&self) -> isize {
+        self.pos - self.padding as isize + self.active.0 as isize
+    }
+
+    /// Whether any of the text cells the band may light falls inside it.
+    pub(crate) fn touches_text(&self, char_count: usize) -> bool {
+        let center = self.center();
+        let (start, end) = self.active;
+        char_count > 0
+            && start < end
+            && center + self.right as isize >= start as isize
+            && center - (self.left as isize) < end as isize
+    }
+
+    /// Index of the text cell closest to the band center.
+    pub(crate) fn peak_index(&self, char_count: usize) -> usize {
+        let (start, end) = self.active;
+        if start < end {
+            self.center().clamp(start as isize, end as isize - 1) as usize
+        } else {
+            self.center().clamp(0, char_count as isize - 1) as usize
+        }
+    }
+}
+
+/// Cells the band crosses per sweep: the text, or its
+/// [`active_range`](ShimmerConfig::active_range), plus both paddings.
+pub(crate) fn band_period(char_count: usize, config: &ShimmerConfig) -> usize {
+    let (start, end) = active_cells(char_count, config);
+    end - start + config.padding * 2
+}
+
+/// [`ShimmerConfig::active_range`] clipped to the text, or the whole text.
+fn active_cells(char_count: usize, config: &ShimmerConfig) -> (usize, usize) {
+    match &config.active_range {
+        Some(range) => {
+            let end = range.end.min(char_count);
+            (range.start.min(end), end)
+        }
+        None => (0, char_count),
+    }
+}
+
+fn intensity_lut() -> &'static [f32] {
+    INTENSITY_LUT.get_or_init(|| {
+        (0..=BAND_HALF_WIDTH)
+            .map(|dist| cosine_falloff(dist, BAND_HALF_WIDTH))
+            .collect()
+    })
+}
+
+/// The cosine falloff sampled at `resolution + 1` evenly spaced fractions of the half-width.
+fn dense_lut(resolution: usize) -> &'static [f32] {
+    let (min, max) = DENSE_LUT_RESOLUTIONS;
+    let resolution = resolution.clamp(min, max);
+    DENSE_LUTS[resolution - min].get_or_init(|| {
+        (0..=resolution)
+            .map(|sample| smooth_falloff(sample as f32 / resolution as f32, 1))
+            .collect()
+    })
+}
+
+/// Like [`smooth_falloff`], interpolating `lut` instead of evaluating the cosine.
+fn sampled_falloff(lut: &[f32], dist: f32, band_half_width: usize) -> f32 {
+    let resolution = lut.len() - 1;
+    let position = dist / band_half_width as f32 * resolution as f32;
+    if position > resolution as f32 {
+        return 0.0;
+    }
+    let sample = (position as usize).min(resolution - 1);
+    let fraction = position - sample as f32;
+    lut[sample] + (lut[sample + 1] - lut[sample]) * fraction
+}
+
+/// Like [`cosine_falloff`] for a fractional distance, zero beyond the half-width.
+fn smooth_falloff(dist: f32, band_half_width: usize) -> f32 {
+    if band_half_width == 0 {
+        // A one-cell band lights whichever cell is nearest its center.
+        if dist <= 0.5 {
+            1.0
+        } else {
+            0.0
+        }
+    } else if dist <= band_half_width as f32 {
+        let x = std::f32::consts::PI * (dist / band_half_width as f32);
+        0.5 * (1.0 + x.cos())
+    } else {
+        0.0
+    }
+}
+
+/// Intensity at `dist` cells from the band center; a half-width of 0 is a one-cell band at
+/// full intensity.
+pub(crate) fn cosine_falloff(dist: usize, band_half_width: usize) -> f32 {
+    if band_half_width > 0 {
+        let x = std::f32::consts::PI * (dist as f32 / band_half_width as f32);
+        0.5 * (1.0 + x.cos())
+    } else if dist == 0 {
+        1.0
+    } else {
+        0.0
+    }
+}
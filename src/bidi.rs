@@ -0,0 +1,83 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+use unicode_bidi::BidiInfo;
+
+use crate::{build_span, coalesce_spans, Frame, ShimmerConfig};
+
+/// Like [`shimmer_spans_with_config`](crate::shimmer_spans_with_config), but sweeps mixed
+/// left-to-right and right-to-left text in visual order.
+///
+/// Each character is lit by its display position after the Unicode bidirectional algorithm
+/// has reordered its runs, including neutral characters such as spaces and punctuation
+/// between runs, so the band crosses an Arabic or Hebrew word from left to right like the rest
+/// of the line. The spans keep the text in logical order for the terminal to reorder as
+/// usual.
+///
+/// Requires the `unicode-bidi` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{shimmer_spans_bidi, shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+/// let styles = |spans: Vec<ratatui::text::Span>| -> Vec<Style> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|_| span.style)).collect()
+/// };
+///
+/// // The Arabic run is displayed reversed, so its first logical letter sits furthest right.
+/// let logical = "Open مرحبا now";
+/// let visual = "Open ابحرم now";
+/// let columns = [0, 1, 2, 3, 4, 9, 8, 7, 6, 5, 10, 11, 12, 13];
+/// for phase in [0.3, 0.45, 0.55, 0.7] {
+///     let bidi = styles(shimmer_spans_bidi(logical, base, phase, &config));
+///     let screen = styles(shimmer_spans_with_config(visual, base, phase, &config));
+///     for (index, column) in columns.into_iter().enumerate() {
+///         assert_eq!(bidi[index], screen[column]);
+///     }
+/// }
+/// ```
+pub fn shimmer_spans_bidi(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    let char_count = text.chars().count();
+    if char_count == 0 {
+        return Vec::new();
+    }
+    let frame = Frame::new(text, char_count, base_style, phase, config);
+    if let Frame::Static(style) = frame {
+        return vec![build_span(text, style)];
+    }
+
+    let visual = visual_positions(text, char_count);
+    let cells = text
+        .chars()
+        .zip(visual)
+        .map(|(ch, position)| (ch, frame.style(position)));
+    coalesce_spans(cells, char_count)
+}
+
+/// Display position of every character, in logical order.
+fn visual_positions(text: &str, char_count: usize) -> Vec<usize> {
+    let info = BidiInfo::new(text, None);
+    let mut visual = (0..char_count).collect::<Vec<_>>();
+    let mut para_start = 0;
+    for para in &info.paragraphs {
+        let levels = info.reordered_levels_per_char(para, para.range.clone());
+        let para_len = text[para.range.clone()].chars().count();
+        let para_levels = &levels[para_start..para_start + para_len];
+        for (position, logical) in BidiInfo::reorder_visual(para_levels)
+            .into_iter()
+            .enumerate()
+        {
+            visual[para_start + logical] = para_start + position;
+        }
+        para_start += para_len;
+    }
+    visual
+}
@@ -0,0 +1,159 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{
+    build_span, char_width, coalesce_spans, shimmer_spans_with_config, ColorMode, ShimmerConfig,
+};
+
+/// Renders several lines with one band sweeping across them along `direction`.
+///
+/// The cell at screen column `col` of line `row` sits at `dx * col + dy * row` along the sweep,
+/// so `(1.0, 0.0)` sweeps left to right like [`shimmer_spans_with_config`], `(0.0, 1.0)` sweeps
+/// top to bottom, and `(1.0, 1.0)` runs diagonally. Negative components reverse the sweep.
+/// Positions are rounded to whole cells, and the band travels from the lowest to the highest
+/// position of any cell, so lines of different widths share the same band.
+///
+/// [`ShimmerConfig::min_animated_len`] applies to the number of positions the band sweeps
+/// over, not to each line, so a vertical sweep lights a column of single chars. Whitespace-only
+/// lines under [`ShimmerConfig::plain_whitespace`] render as a single static span.
+///
+/// [`shimmer_spans_with_config`]: crate::shimmer_spans_with_config
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::{Line, Span};
+/// use tui_shimmer::{shimmer_block, shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// let styles = |spans: &[Span]| -> Vec<Style> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|_| span.style)).collect()
+/// };
+///
+/// let rows = ["Fetching", "Resolves", "Compiles"];
+/// for step in 0..=20 {
+///     let phase = step as f32 / 20.0;
+///     // A horizontal sweep renders each line like a single-line shimmer of the same width.
+///     let block = shimmer_block(&rows, base, phase, (1.0, 0.0), &config);
+///     for (line, row) in block.iter().zip(rows) {
+///         assert_eq!(line.spans, shimmer_spans_with_config(row, base, phase, &config));
+///     }
+///
+///     // A vertical sweep over a column matches a horizontal one over the same chars.
+///     let column = shimmer_block(&["a", "b", "c", "d", "e"], base, phase, (0.0, 1.0), &config);
+///     let flat: Vec<Style> = column.iter().flat_map(|line: &Line| styles(&line.spans)).collect();
+///     assert_eq!(flat, styles(&shimmer_spans_with_config("abcde", base, phase, &config)));
+/// }
+///
+/// // Diagonal sweeps handle lines of different widths.
+/// let lines = shimmer_block(&["Fetching index", "Resolving deps", "Done"], base, 0.5, (1.0, 1.0), &config);
+/// assert_eq!(lines.len(), 3);
+/// ```
+pub fn shimmer_block(
+    lines: &[&str],
+    base_style: Style,
+    phase: f32,
+    direction: (f32, f32),
+    config: &ShimmerConfig,
+) -> Vec<Line<'static>> {
+    let (dx, dy) = direction;
+    let project = |col: usize, row: usize| dx * col as f32 + dy * row as f32;
+
+    let mut range: Option<(f32, f32)> = None;
+    for (row, line) in lines.iter().enumerate() {
+        let mut col = 0;
+        for ch in line.chars() {
+            let position = project(col, row);
+            range = Some(match range {
+                Some((min, max)) => (min.min(position), max.max(position)),
+                None => (position, position),
+            });
+            col += char_width(ch);
+        }
+    }
+    let Some((min, max)) = range else {
+        return lines.iter().map(|_| Line::default()).collect();
+    };
+
+    let cell_count = (max - min).round() as usize + 1;
+    let band = Band::new(cell_count, phase, config);
+    let painter = CellPainter::new(base_style, config);
+    let animated = painter.mode() != ColorMode::Plain && cell_count >= config.min_animated_len;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(row, line)| {
+            if !animated
+                || line.is_empty()
+                || (config.plain_whitespace && line.chars().all(char::is_whitespace))
+            {
+                return Line::from(build_span(*line, base_style));
+            }
+            let mut col = 0;
+            let cells = line.chars().map(|ch| {
+                let index = (project(col, row) - min).round() as usize;
+                col += char_width(ch);
+                (ch, painter.style(index, band.intensity(index)))
+            });
+            Line::from(coalesce_spans(cells, line.len()))
+        })
+        .collect()
+}
+
+/// Renders a skeleton placeholder: `cell_count` copies of `block_glyph` with the band sweeping
+/// across them.
+///
+/// Use `'█'` for a solid bar colored by the foreground. A blank glyph such as `' '` shows no
+/// foreground, so for whitespace the band's colors are applied to the background instead and
+/// the base foreground sets the resting color. The cells otherwise render exactly like
+/// [`shimmer_spans_with_config`] over the same number of chars.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::testing::spans_to_plain;
+/// use tui_shimmer::{band_geometry, shimmer_blocks, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// let cell_styles = |glyph, phase| -> Vec<Style> {
+///     shimmer_blocks(16, glyph, base, phase, &config)
+///         .iter()
+///         .flat_map(|span| span.content.chars().map(|_| span.style))
+///         .collect()
+/// };
+///
+/// assert_eq!(spans_to_plain(&shimmer_blocks(16, '█', base, 0.3, &config)), "█".repeat(16));
+/// for phase in [0.4, 0.6] {
+///     let peak = band_geometry(&"█".repeat(16), phase, &config).peak_col as usize;
+///     let styles = cell_styles('█', phase);
+///     assert_eq!(styles.len(), 16);
+///     assert_ne!(styles[peak], styles[0]);
+///     assert_ne!(styles[peak], styles[15]);
+/// }
+/// // Blank skeletons shimmer through the background.
+/// assert!(cell_styles(' ', 0.5).iter().all(|style| style.bg.is_some()));
+/// ```
+pub fn shimmer_blocks(
+    cell_count: usize,
+    block_glyph: char,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    let text: String = std::iter::repeat_n(block_glyph, cell_count).collect();
+    let mut spans = shimmer_spans_with_config(&text, base_style, phase, config);
+    if block_glyph.is_whitespace() {
+        for span in &mut spans {
+            if let Some(fg) = span.style.fg {
+                span.style = span.style.bg(fg);
+            }
+        }
+    }
+    spans
+}
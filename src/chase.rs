@@ -0,0 +1,95 @@
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::color::{color_to_rgb, CellPainter};
+use crate::{build_span, coalesce_spans, is_animated, ShimmerConfig};
+
+/// One colored band in a [`shimmer_chase`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChaseBand {
+    /// Color the band blends toward. Colors without an RGB value, such as
+    /// [`Color::Reset`], fall back to white.
+    pub color: Color,
+    /// Phase offset of this band within the cycle, added to the frame phase.
+    pub offset: f32,
+}
+
+impl ChaseBand {
+    /// Creates a band of `color` offset by `offset` of a cycle.
+    pub const fn new(color: Color, offset: f32) -> Self {
+        Self { color, offset }
+    }
+}
+
+/// Renders several colored bands chasing each other across `text`.
+///
+/// Every band uses the width and padding of `config` and sits at `phase + band.offset`. Each
+/// cell takes its color from whichever band lights it most, so evenly spaced offsets give a
+/// festive red/green/blue chase. The fallback color paths ignore band colors.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{band_geometry, shimmer_chase, ChaseBand, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let text = "Happy holidays from all of us, and cheers!";
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// let bands = [
+///     ChaseBand::new(Color::Red, 0.0),
+///     ChaseBand::new(Color::Green, 0.25),
+///     ChaseBand::new(Color::Blue, 0.5),
+/// ];
+/// let fgs: Vec<Option<Color>> = shimmer_chase(text, base, 0.25, &bands, &config)
+///     .iter()
+///     .flat_map(|span| span.content.chars().map(|_| span.style.fg))
+///     .collect();
+///
+/// // Each band's peak cell blends toward that band's own color.
+/// let [red, green, blue] =
+///     bands.map(|band| fgs[band_geometry(text, 0.25 + band.offset, &config).peak_col as usize]);
+/// assert!(matches!(red, Some(Color::Rgb(r, g, b)) if r > 150 && g < 60 && b < 60));
+/// assert!(matches!(green, Some(Color::Rgb(r, g, b)) if g > 100 && r < 60 && b < 60));
+/// assert!(matches!(blue, Some(Color::Rgb(r, g, b)) if b > 100 && r < 60 && g < 60));
+/// ```
+pub fn shimmer_chase(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    bands: &[ChaseBand],
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    let char_count = text.chars().count();
+    if char_count == 0 {
+        return Vec::new();
+    }
+    if !is_animated(text, config) {
+        return vec![build_span(text, base_style)];
+    }
+
+    let painter = CellPainter::new(base_style, config);
+    let bands: Vec<(Band, (u8, u8, u8))> = bands
+        .iter()
+        .map(|band| {
+            (
+                Band::new(char_count, phase + band.offset, config),
+                color_to_rgb(band.color).unwrap_or((255, 255, 255)),
+            )
+        })
+        .collect();
+
+    let cells = text.chars().enumerate().map(|(index, ch)| {
+        let brightest = bands
+            .iter()
+            .map(|(band, rgb)| (band.intensity(index), *rgb))
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+        let style = match brightest {
+            Some((intensity, rgb)) => painter.style_toward(index, intensity, rgb),
+            None => painter.style(index, 0.0),
+        };
+        (ch, style)
+    });
+    coalesce_spans(cells, char_count)
+}
@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "std")]
+use crate::SHIMMER_SWEEP_SECONDS;
+
+#[cfg(feature = "std")]
+static PROCESS_START: OnceLock<Instant> = OnceLock::new();
+/// Global time scale stored as `f32` bits; `0x3f80_0000` is `1.0`.
+static TIME_SCALE: AtomicU32 = AtomicU32::new(0x3f80_0000);
+/// Whether the terminal has focus, as last reported to [`set_focused`].
+static FOCUSED: AtomicBool = AtomicBool::new(true);
+/// Whether shimmers animate at all, as last set by [`set_shimmer_enabled`].
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Sets a process-wide multiplier for shimmer time.
+///
+/// `0.5` runs every animation at half speed, `2.0` at double speed and `0.0` freezes them.
+/// Negative and non-finite values are treated as `0.0`. The scale applies to the global clock
+/// behind [`shimmer_spans_with_style`](crate::shimmer_spans_with_style) and to every tick of
+/// the stateful types unless they opt out, multiplying with their own speed.
+///
+/// The global clock scales its total elapsed time, so changing the scale moves its band;
+/// stateful instances scale each tick and continue from where they are.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tui_shimmer::{set_time_scale, ShimmerState};
+///
+/// let mut scaled = ShimmerState::new().sweep_seconds(2.0);
+/// let mut unscaled = ShimmerState::new().sweep_seconds(2.0).ignore_time_scale();
+///
+/// set_time_scale(0.5);
+/// scaled.tick(Duration::from_millis(600));
+/// unscaled.tick(Duration::from_millis(600));
+/// assert!((scaled.phase() - unscaled.phase() / 2.0).abs() < 1e-4);
+///
+/// // A scale of 0.0 freezes every instance that follows it.
+/// set_time_scale(0.0);
+/// let frozen = scaled.phase();
+/// scaled.tick(Duration::from_millis(600));
+/// assert_eq!(scaled.phase(), frozen);
+///
+/// set_time_scale(1.0);
+/// ```
+pub fn set_time_scale(scale: f32) {
+    let scale = if scale.is_finite() {
+        scale.max(0.0)
+    } else {
+        0.0
+    };
+    TIME_SCALE.store(scale.to_bits(), Ordering::Relaxed);
+}
+
+/// Returns the process-wide time scale set by [`set_time_scale`]. Defaults to `1.0`.
+pub fn time_scale() -> f32 {
+    f32::from_bits(TIME_SCALE.load(Ordering::Relaxed))
+}
+
+/// Reports whether the terminal window has focus, for every stateful shimmer at once.
+///
+/// Forward the terminal's focus-in and focus-out events here (crossterm reports them as
+/// `Event::FocusGained` and `Event::FocusLost` once focus change reporting is enabled). While
+/// unfocused, every [`ShimmerState`](crate::ShimmerState), and the types built on it, follows
+/// its [`FocusPolicy`](crate::FocusPolicy) as if its own
+/// [`set_focused`](crate::ShimmerState::set_focused) had been called with `false`. The global
+/// clock behind [`shimmer_spans_with_style`](crate::shimmer_spans_with_style) is unaffected.
+pub fn set_focused(focused: bool) {
+    FOCUSED.store(focused, Ordering::Relaxed);
+}
+
+/// Returns the focus last reported to [`set_focused`]. Defaults to `true`.
+pub fn is_focused() -> bool {
+    FOCUSED.load(Ordering::Relaxed)
+}
+
+/// Turns every shimmer in the process on or off, such as for a "reduce motion" or low power
+/// setting.
+///
+/// While disabled, every render function returns text in its base style without animation,
+/// as if the color mode were [`ColorMode::Plain`](crate::ColorMode::Plain). This includes the
+/// explicit-phase functions; set [`ShimmerConfig::ignore_shimmer_enabled`] on configs that
+/// should keep animating regardless.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::{set_shimmer_enabled, shimmer_spans_with_config, ShimmerConfig};
+///
+/// let base = Style::default().fg(Color::Cyan);
+/// let config = ShimmerConfig::default();
+/// set_shimmer_enabled(false);
+/// assert_eq!(
+///     shimmer_spans_with_config("Loading...", base, 0.5, &config),
+///     vec![Span::styled("Loading...", base)]
+/// );
+/// set_shimmer_enabled(true);
+/// ```
+///
+/// [`ShimmerConfig::ignore_shimmer_enabled`]: crate::ShimmerConfig::ignore_shimmer_enabled
+pub fn set_shimmer_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether shimmers animate, as set by [`set_shimmer_enabled`]. Defaults to `true`.
+pub fn shimmer_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn shimmer_phase_from_elapsed() -> f32 {
+    shimmer_phase_at(Instant::now())
+}
+
+/// Returns the phase of the global shimmer clock at `at`.
+///
+/// This is the phase [`shimmer_spans_with_style`](crate::shimmer_spans_with_style) would use
+/// if called at `at`. Sample [`Instant::now`] once per frame and pass the phase to every
+/// `*_at_phase` or phase-taking helper so all widgets in the frame agree. Instants before the
+/// clock started, which is the first time it is read, give phase `0.0`.
+///
+/// Requires the `std` feature (enabled by default).
+#[cfg(feature = "std")]
+pub fn shimmer_phase_at(at: Instant) -> f32 {
+    if SHIMMER_SWEEP_SECONDS <= 0.0 {
+        return 0.0;
+    }
+    let start = PROCESS_START.get_or_init(|| at);
+    let elapsed =
+        at.saturating_duration_since(*start).as_secs_f32() * time_scale() / SHIMMER_SWEEP_SECONDS;
+    elapsed.rem_euclid(1.0)
+}
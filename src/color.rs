@@ -0,0 +1,349 @@
+use ratatui_core::style::{Color, Modifier, Style};
+
+use crate::indexed::{indexed_to_rgb, resolve_indexed, rgb_to_indexed_dithered};
+use crate::ramp::{default_stops, modifier_at};
+use crate::{
+    BandTransform, ColorMode, EffectKind, HighlightStyle, IndexedResolver, ShadowConfig,
+    ShimmerConfig, ShimmerGradient,
+};
+
+/// Per-frame inputs for resolving cell styles, computed once per render.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CellPainter<'a> {
+    base_style: Style,
+    base_rgb: (u8, u8, u8),
+    highlight_rgb: (u8, u8, u8),
+    mode: ColorMode,
+    dither: bool,
+    color_quantization: u8,
+    indexed_resolver: Option<IndexedResolver>,
+    stops: &'a [(f32, Modifier)],
+    /// Intensity below which the built-in RGB ramp leaves cells regular weight.
+    bold_threshold: f32,
+    gradient: Option<&'a ShimmerGradient>,
+    shadow: Option<ShadowConfig>,
+    max_highlight: f32,
+    band_transform: BandTransform,
+    /// Whether the band darkens rather than lights, see [`EffectKind::ShadowSweep`].
+    invert: bool,
+}
+
+impl<'a> CellPainter<'a> {
+    pub(crate) fn new(base_style: Style, config: &'a ShimmerConfig) -> Self {
+        Self::with_mode(base_style, config, config.render_mode())
+    }
+
+    /// Like [`CellPainter::new`] with the color mode already resolved.
+    pub(crate) fn with_mode(base_style: Style, config: &'a ShimmerConfig, mode: ColorMode) -> Self {
+        let rgb_path = matches!(mode, ColorMode::TrueColor | ColorMode::Indexed256);
+        let (stops, bold_threshold) = match &config.modifier_ramp {
+            Some(ramp) => (ramp.stops(), 0.0),
+            None if rgb_path => (default_stops(true), config.bold_threshold),
+            None => (default_stops(false), 0.0),
+        };
+        let base_rgb = base_style
+            .fg
+            .and_then(color_to_rgb)
+            .unwrap_or((128, 128, 128));
+        let highlight_rgb = resolve_highlight(config, base_rgb);
+        let invert = config.effect == EffectKind::ShadowSweep;
+        // A shadow sweep blends from the dark color instead of the base.
+        let base_rgb = match config.dark.and_then(color_to_rgb) {
+            Some(dark) if invert => dark,
+            _ => base_rgb,
+        };
+        Self {
+            base_style,
+            base_rgb,
+            highlight_rgb,
+            mode,
+            dither: config.dither,
+            color_quantization: config.color_quantization,
+            indexed_resolver: config.indexed_resolver,
+            stops,
+            bold_threshold,
+            gradient: config.gradient.as_ref(),
+            shadow: config.shadow,
+            max_highlight: config.max_highlight.clamp(0.0, 1.0),
+            band_transform: config.band_transform,
+            invert,
+        }
+    }
+
+    pub(crate) fn band_transform(&self) -> BandTransform {
+        self.band_transform
+    }
+
+    /// The resolved color mode; never [`ColorMode::Auto`].
+    pub(crate) fn mode(&self) -> ColorMode {
+        self.mode
+    }
+
+    pub(crate) fn highlight_rgb(&self) -> (u8, u8, u8) {
+        self.highlight_rgb
+    }
+
+    pub(crate) fn base_rgb(&self) -> (u8, u8, u8) {
+        self.base_rgb
+    }
+
+    /// Style of the cell at `index` for the given band intensity.
+    pub(crate) fn style(&self, index: usize, intensity: f32) -> Style {
+        self.style_toward(index, intensity, self.highlight_rgb)
+    }
+
+    /// Like [`CellPainter::style`], blending toward `highlight_rgb` instead of the configured
+    /// highlight.
+    pub(crate) fn style_toward(
+        &self,
+        index: usize,
+        intensity: f32,
+        highlight_rgb: (u8, u8, u8),
+    ) -> Style {
+        self.paint(index, intensity, highlight_rgb, self.base_rgb)
+    }
+
+    /// Trailing distance of the configured [`ShadowConfig`], if any.
+    pub(crate) fn shadow_offset(&self) -> Option<isize> {
+        self.shadow.map(|shadow| shadow.offset)
+    }
+
+    /// Like [`CellPainter::style`], with the base darkened by the shadow band's intensity at
+    /// this cell.
+    pub(crate) fn style_shadowed(&self, index: usize, intensity: f32, shadow: f32) -> Style {
+        let strength = self.shadow.map_or(0.0, |shadow| shadow.strength);
+        let base_rgb = blend_rgb((0, 0, 0), self.base_rgb, strength.clamp(0.0, 1.0) * shadow);
+        self.paint(index, intensity, self.highlight_rgb, base_rgb)
+    }
+
+    fn paint(
+        &self,
+        index: usize,
+        intensity: f32,
+        highlight_rgb: (u8, u8, u8),
+        base_rgb: (u8, u8, u8),
+    ) -> Style {
+        // Resting cells keep one solid color; dithering them would show as noise.
+        let dither = self.dither && intensity > 0.0;
+        let intensity = if self.invert {
+            1.0 - intensity.clamp(0.0, 1.0)
+        } else {
+            intensity
+        };
+        match self.mode {
+            ColorMode::TrueColor | ColorMode::Indexed256 => {
+                let rgb = match self.gradient {
+                    Some(gradient) => gradient.sample(intensity, base_rgb),
+                    None => {
+                        let highlight = intensity.clamp(0.0, 1.0) * self.max_highlight;
+                        blend_rgb(highlight_rgb, base_rgb, highlight)
+                    }
+                };
+                let rgb = quantize_rgb(rgb, base_rgb, self.color_quantization);
+                // Custom RGB is intentional for shimmer.
+                #[allow(clippy::disallowed_methods)]
+                let color = match self.mode {
+                    ColorMode::TrueColor => Color::Rgb(rgb.0, rgb.1, rgb.2),
+                    _ if dither && self.indexed_resolver.is_none() => {
+                        Color::Indexed(rgb_to_indexed_dithered(rgb, index))
+                    }
+                    _ => Color::Indexed(resolve_indexed(self.indexed_resolver, rgb)),
+                };
+                self.base_style.fg(color)
+            }
+            ColorMode::Basic16 => self.base_style.fg(level_color(intensity)),
+            ColorMode::ModifierOnly => self.base_style,
+            ColorMode::PeakModifier(modifier) if intensity >= PEAK_MODIFIER_THRESHOLD => {
+                return self.base_style.add_modifier(modifier);
+            }
+            ColorMode::PeakModifier(_) => return self.base_style,
+            ColorMode::Auto | ColorMode::Plain => return self.base_style,
+        }
+        .add_modifier(self.modifier(intensity))
+    }
+
+    /// Modifiers from the ramp at `intensity`.
+    fn modifier(&self, intensity: f32) -> Modifier {
+        if intensity < self.bold_threshold {
+            Modifier::empty()
+        } else {
+            modifier_at(self.stops, intensity)
+        }
+    }
+}
+
+/// Applies [`ShimmerConfig::base_wash`] to `base_style` at `wash_phase` (0.0..1.0).
+///
+/// The base moves from the first color to the second and back once per cycle, easing at both
+/// ends. Modes without an RGB path keep `base_style` unchanged.
+pub(crate) fn wash_style(base_style: Style, config: &ShimmerConfig, wash_phase: f32) -> Style {
+    let Some((from, to, _)) = config.base_wash else {
+        return base_style;
+    };
+    let mode = config.render_mode();
+    if !matches!(mode, ColorMode::TrueColor | ColorMode::Indexed256) {
+        return base_style;
+    }
+    let (Some(from), Some(to)) = (color_to_rgb(from), color_to_rgb(to)) else {
+        return base_style;
+    };
+    let amount = 0.5 * (1.0 - (std::f32::consts::TAU * wash_phase).cos());
+    let rgb = blend_rgb(to, from, amount);
+    // Custom RGB is intentional for shimmer.
+    #[allow(clippy::disallowed_methods)]
+    let color = match mode {
+        ColorMode::TrueColor => Color::Rgb(rgb.0, rgb.1, rgb.2),
+        _ => Color::Indexed(resolve_indexed(config.indexed_resolver, rgb)),
+    };
+    base_style.fg(color)
+}
+
+/// Minimum intensity of the cells that get the [`ColorMode::PeakModifier`] modifier.
+const PEAK_MODIFIER_THRESHOLD: f32 = 0.6;
+
+/// Share of the way an automatic highlight moves from the base toward white or black.
+const AUTO_HIGHLIGHT_AMOUNT: f32 = 0.6;
+/// Bases brighter than this relative luminance get a darker automatic highlight.
+const AUTO_HIGHLIGHT_DARKEN_ABOVE: f32 = 0.75;
+
+fn resolve_highlight(config: &ShimmerConfig, base_rgb: (u8, u8, u8)) -> (u8, u8, u8) {
+    if let HighlightStyle::Lighten(amount) = config.highlight_style {
+        return lighten_rgb(base_rgb, amount);
+    }
+    let highlight = config.highlight.and_then(color_to_rgb);
+    if !config.auto_highlight || highlight.is_some_and(|rgb| rgb != base_rgb) {
+        return highlight.unwrap_or((255, 255, 255));
+    }
+    let target = if luminance(base_rgb) > AUTO_HIGHLIGHT_DARKEN_ABOVE {
+        (0, 0, 0)
+    } else {
+        (255, 255, 255)
+    };
+    blend_rgb(target, base_rgb, AUTO_HIGHLIGHT_AMOUNT)
+}
+
+/// Raises the HSL lightness of `rgb` by `amount`, keeping hue and saturation.
+///
+/// The band blends from the base to this color in RGB; both share a hue, so every cell in
+/// between keeps it too.
+fn lighten_rgb(rgb: (u8, u8, u8), amount: f32) -> (u8, u8, u8) {
+    let amount = if amount.is_finite() {
+        amount.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (hue, saturation, lightness) = rgb_to_hsl(rgb);
+    hsl_to_rgb(hue, saturation, (lightness + amount).min(1.0))
+}
+
+/// Converts to hue (0.0..6.0, in sextants), saturation and lightness (both 0.0..=1.0).
+fn rgb_to_hsl((r, g, b): (u8, u8, u8)) -> (f32, f32, f32) {
+    let (r, g, b) = (
+        f32::from(r) / 255.0,
+        f32::from(g) / 255.0,
+        f32::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    let lightness = (max + min) / 2.0;
+    if chroma == 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+    let hue = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+    (hue, saturation, lightness)
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (u8, u8, u8) {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |value: f32| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+/// Relative luminance (0.0..=1.0) of an sRGB color, without gamma correction.
+fn luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    (0.2126 * f32::from(r) + 0.7152 * f32::from(g) + 0.0722 * f32::from(b)) / 255.0
+}
+
+fn level_color(intensity: f32) -> Color {
+    if intensity < 0.2 {
+        Color::DarkGray
+    } else if intensity < 0.6 {
+        Color::Gray
+    } else {
+        Color::White
+    }
+}
+
+pub(crate) fn blend_rgb(highlight: (u8, u8, u8), base: (u8, u8, u8), amount: f32) -> (u8, u8, u8) {
+    let amount = amount.clamp(0.0, 1.0);
+    let blend = |from: u8, to: u8| -> u8 {
+        let from = from as f32;
+        let to = to as f32;
+        (from + (to - from) * amount).round().clamp(0.0, 255.0) as u8
+    };
+
+    (
+        blend(base.0, highlight.0),
+        blend(base.1, highlight.1),
+        blend(base.2, highlight.2),
+    )
+}
+
+/// Rounds each channel's offset from `base` to a multiple of `step`, keeping it in range.
+fn quantize_rgb(rgb: (u8, u8, u8), base: (u8, u8, u8), step: u8) -> (u8, u8, u8) {
+    if step <= 1 {
+        return rgb;
+    }
+    let quantize = |channel: u8, base: u8| -> u8 {
+        let step = f32::from(step);
+        let offset = ((f32::from(channel) - f32::from(base)) / step).round() * step;
+        (f32::from(base) + offset).clamp(0.0, 255.0) as u8
+    };
+    (
+        quantize(rgb.0, base.0),
+        quantize(rgb.1, base.1),
+        quantize(rgb.2, base.2),
+    )
+}
+
+pub(crate) fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Black => Some((0, 0, 0)),
+        Color::Red => Some((170, 0, 0)),
+        Color::Green => Some((0, 170, 0)),
+        Color::Yellow => Some((170, 85, 0)),
+        Color::Blue => Some((0, 0, 170)),
+        Color::Magenta => Some((170, 0, 170)),
+        Color::Cyan => Some((0, 170, 170)),
+        Color::Gray => Some((170, 170, 170)),
+        Color::DarkGray => Some((85, 85, 85)),
+        Color::LightRed => Some((255, 85, 85)),
+        Color::LightGreen => Some((85, 255, 85)),
+        Color::LightYellow => Some((255, 255, 85)),
+        Color::LightBlue => Some((85, 85, 255)),
+        Color::LightMagenta => Some((255, 85, 255)),
+        Color::LightCyan => Some((85, 255, 255)),
+        Color::White => Some((255, 255, 255)),
+        Color::Indexed(code) => Some(indexed_to_rgb(code)),
+        Color::Reset => None,
+    }
+}
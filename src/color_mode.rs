@@ -0,0 +1,358 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use ratatui_core::style::Modifier;
+
+#[cfg(feature = "std")]
+static COLOR_MODE_CACHE: OnceLock<ColorMode> = OnceLock::new();
+/// Mode set by [`set_color_support`], taking precedence over detection; see
+/// [`encode_override`]. 0 is no override.
+static COLOR_SUPPORT_OVERRIDE: AtomicU32 = AtomicU32::new(0);
+
+/// Color capability used to resolve per-cell styles.
+///
+/// Every explicit mode is resolved without reading the environment, and each keeps to its own
+/// kind of color:
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let base = Style::default().fg(Color::Cyan);
+/// let render = |color_mode| -> Vec<Span<'static>> {
+///     let config = ShimmerConfig { color_mode, ..ShimmerConfig::default() };
+///     shimmer_spans_with_config("Loading the index...", base, 0.5, &config)
+/// };
+/// let colors = |spans: &[Span]| spans.iter().map(|span| span.style.fg.unwrap()).collect::<Vec<_>>();
+///
+/// assert!(colors(&render(ColorMode::TrueColor)).iter().all(|fg| matches!(fg, Color::Rgb(..))));
+/// assert!(colors(&render(ColorMode::Indexed256)).iter().all(|fg| matches!(fg, Color::Indexed(_))));
+/// assert!(colors(&render(ColorMode::Basic16))
+///     .iter()
+///     .all(|fg| matches!(fg, Color::DarkGray | Color::Gray | Color::White)));
+/// assert!(colors(&render(ColorMode::ModifierOnly)).iter().all(|fg| *fg == Color::Cyan));
+/// assert_eq!(render(ColorMode::Plain), vec![Span::styled("Loading the index...", base)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// Detect from the environment (`NO_COLOR`, `CLICOLOR_FORCE`, `CLICOLOR`, `COLORTERM`,
+    /// `TERM`) as described in [`ColorMode::detect`].
+    ///
+    /// This is the only mode the environment affects; every other mode is used as is, even
+    /// with `CLICOLOR_FORCE` or `NO_COLOR` set.
+    ///
+    /// The result is cached for the life of the process. Without the `std` feature the
+    /// environment is not read and this always resolves to [`ColorMode::Basic16`].
+    #[default]
+    Auto,
+    /// Blend the base color toward white as 24-bit RGB.
+    TrueColor,
+    /// Blend as in [`ColorMode::TrueColor`], then map to the nearest xterm 256-color entry.
+    ///
+    /// A band blending across a single palette step shows as two long runs of one index each.
+    /// With [`ShimmerConfig::dither`] the cells in between alternate between both entries,
+    /// while cells outside the band keep one solid color:
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::Indexed256,
+    ///     band_half_width: 20,
+    ///     padding: 20,
+    ///     highlight: Some(Color::Rgb(18, 18, 18)),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let base = Style::default().fg(Color::Rgb(8, 8, 8));
+    /// let switches = |config: &ShimmerConfig| {
+    ///     let styles = shimmer_styles(&"-".repeat(41), base, 0.5, config);
+    ///     styles.windows(2).filter(|pair| pair[0].fg != pair[1].fg).count()
+    /// };
+    /// assert_eq!(switches(&config), 2);
+    /// let dithered = ShimmerConfig { dither: true, ..config.clone() };
+    /// assert!(switches(&dithered) >= 10);
+    ///
+    /// // Resting cells are not dithered.
+    /// let resting = shimmer_styles("Loading...", base.fg(Color::Rgb(100, 60, 140)), 0.0, &dithered);
+    /// assert!(resting.iter().all(|style| *style == resting[0]));
+    /// ```
+    ///
+    /// [`ShimmerConfig::dither`]: crate::ShimmerConfig::dither
+    Indexed256,
+    /// Map intensity to `DarkGray`/`Gray`/`White` with `DIM`/`BOLD` modifiers.
+    Basic16,
+    /// Keep the base foreground and animate only `DIM`/`BOLD` modifiers.
+    ModifierOnly,
+    /// Last resort for terminals where even `DIM`/`BOLD` are invisible: add the given modifier,
+    /// such as [`Modifier::SLOW_BLINK`] or [`Modifier::REVERSED`], to the band's peak cells and
+    /// keep every other cell at the base style.
+    ///
+    /// No colors are emitted and a frame has at most three spans. [`ColorMode::Auto`] never
+    /// resolves to this mode.
+    ///
+    /// ```rust
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_shimmer::{band_geometry, shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::PeakModifier(Modifier::REVERSED),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let text = "Waiting for the lock file";
+    /// let spans = shimmer_spans_with_config(text, Style::default(), 0.5, &config);
+    ///
+    /// assert_eq!(spans.len(), 3);
+    /// assert!(spans.iter().all(|span| span.style.fg.is_none() && span.style.bg.is_none()));
+    /// assert_eq!(spans[0].style, Style::default());
+    /// assert_eq!(spans[1].style, Style::default().add_modifier(Modifier::REVERSED));
+    /// assert_eq!(spans[2].style, Style::default());
+    /// // The modified cells surround the peak.
+    /// let peak = band_geometry(text, 0.5, &config).peak_col as usize;
+    /// let start = spans[0].content.chars().count();
+    /// assert!((start..start + spans[1].content.chars().count()).contains(&peak));
+    /// ```
+    PeakModifier(Modifier),
+    /// Render the text with the base style and no animation.
+    Plain,
+}
+
+impl ColorMode {
+    /// Picks a color tier from environment variables looked up through `var`.
+    ///
+    /// This is the detection behind [`ColorMode::Auto`], for callers that read the environment
+    /// elsewhere, such as a remote client's variables. In order:
+    ///
+    /// 1. `NO_COLOR` set: [`ColorMode::Basic16`].
+    /// 2. `CLICOLOR_FORCE` set to anything but `0`: [`ColorMode::TrueColor`].
+    /// 3. `CLICOLOR=0`: [`ColorMode::Basic16`].
+    /// 4. `COLORTERM` containing `truecolor` or `24bit`: [`ColorMode::TrueColor`].
+    /// 5. `TERM` naming a known true-color terminal (such as `foot` or `xterm-kitty`) or ending
+    ///    in `-direct`: [`ColorMode::TrueColor`].
+    /// 6. `TERM` ending in `-256color`: [`ColorMode::Indexed256`].
+    /// 7. Anything else, including `linux`, `vt100` and `*-16color`: [`ColorMode::Basic16`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_shimmer::ColorMode;
+    ///
+    /// let env = |term: &'static str| move |name: &str| (name == "TERM").then(|| term.to_owned());
+    /// for (term, mode) in [
+    ///     ("xterm-256color", ColorMode::Indexed256),
+    ///     ("tmux-256color", ColorMode::Indexed256),
+    ///     ("foot-direct", ColorMode::TrueColor),
+    ///     ("xterm-direct", ColorMode::TrueColor),
+    ///     ("xterm-kitty", ColorMode::TrueColor),
+    ///     ("xterm-16color", ColorMode::Basic16),
+    ///     ("linux", ColorMode::Basic16),
+    ///     ("vt100", ColorMode::Basic16),
+    /// ] {
+    ///     assert_eq!(ColorMode::detect(env(term)), mode, "TERM={term}");
+    /// }
+    ///
+    /// // COLORTERM still upgrades a 256-color TERM, and NO_COLOR still downgrades a direct one.
+    /// let with = |extra: &'static str, term: &'static str| {
+    ///     move |name: &str| match name {
+    ///         "TERM" => Some(term.to_owned()),
+    ///         _ if name == extra => Some("truecolor".to_owned()),
+    ///         _ => None,
+    ///     }
+    /// };
+    /// assert_eq!(ColorMode::detect(with("COLORTERM", "xterm-256color")), ColorMode::TrueColor);
+    /// assert_eq!(ColorMode::detect(with("NO_COLOR", "foot-direct")), ColorMode::Basic16);
+    /// ```
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> ColorMode {
+        detect_color_mode(var)
+    }
+
+    /// Resolves this mode as rendering does, but detecting from `var` instead of the process
+    /// environment.
+    ///
+    /// An explicit mode always wins: only [`ColorMode::Auto`] consults the environment, so
+    /// `CLICOLOR_FORCE`, `NO_COLOR` and the rest never override a mode the application chose.
+    /// `Auto` resolves through [`ColorMode::detect`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_shimmer::ColorMode;
+    ///
+    /// let env = |name: &str| match name {
+    ///     "CLICOLOR_FORCE" => Some("1".to_owned()),
+    ///     "TERM" => Some("xterm-256color".to_owned()),
+    ///     _ => None,
+    /// };
+    /// assert_eq!(ColorMode::Auto.resolve_with(env), ColorMode::TrueColor);
+    /// assert_eq!(ColorMode::Indexed256.resolve_with(env), ColorMode::Indexed256);
+    /// ```
+    pub fn resolve_with(self, var: impl Fn(&str) -> Option<String>) -> ColorMode {
+        match self {
+            ColorMode::Auto => detect_color_mode(var),
+            mode => mode,
+        }
+    }
+
+    /// Resolves against the process environment. Precedence, highest first: an explicit
+    /// (non-`Auto`) mode, then [`set_color_support`](crate::set_color_support), then
+    /// environment detection.
+    pub(crate) fn resolve(self) -> ColorMode {
+        match self {
+            ColorMode::Auto => detected_color_mode(),
+            mode => mode,
+        }
+    }
+}
+
+/// Color support reported by the application's own terminal backend, for
+/// [`ShimmerConfig::capabilities`].
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{ColorMode, TerminalCapabilities};
+///
+/// let caps = TerminalCapabilities { truecolor: false, colors_256: true };
+/// assert_eq!(caps.color_mode(), ColorMode::Indexed256);
+/// ```
+///
+/// [`ShimmerConfig::capabilities`]: crate::ShimmerConfig::capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TerminalCapabilities {
+    /// The terminal renders 24-bit RGB colors.
+    pub truecolor: bool,
+    /// The terminal renders the xterm 256-color palette.
+    pub colors_256: bool,
+}
+
+impl TerminalCapabilities {
+    /// The color path [`ColorMode::Auto`] resolves to with these capabilities.
+    pub fn color_mode(&self) -> ColorMode {
+        if self.truecolor {
+            ColorMode::TrueColor
+        } else if self.colors_256 {
+            ColorMode::Indexed256
+        } else {
+            ColorMode::Basic16
+        }
+    }
+}
+
+/// Terminal name prefixes with a known color tier, checked before the `TERM` suffix.
+const KNOWN_TERMINALS: [(&str, ColorMode); 8] = [
+    ("alacritty", ColorMode::TrueColor),
+    ("contour", ColorMode::TrueColor),
+    ("foot", ColorMode::TrueColor),
+    ("wezterm", ColorMode::TrueColor),
+    ("xterm-ghostty", ColorMode::TrueColor),
+    ("xterm-kitty", ColorMode::TrueColor),
+    ("iterm2", ColorMode::TrueColor),
+    ("rio", ColorMode::TrueColor),
+];
+
+/// Environment-based color detection; see [`ColorMode::detect`] for the rules.
+pub(crate) fn detect_color_mode(var: impl Fn(&str) -> Option<String>) -> ColorMode {
+    if var("NO_COLOR").is_some() {
+        return ColorMode::Basic16;
+    }
+    if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+        return ColorMode::TrueColor;
+    }
+    if var("CLICOLOR").is_some_and(|value| value == "0") {
+        return ColorMode::Basic16;
+    }
+    if var("COLORTERM").is_some_and(|value| {
+        let lower = value.to_lowercase();
+        lower.contains("truecolor") || lower.contains("24bit")
+    }) {
+        return ColorMode::TrueColor;
+    }
+
+    let term = var("TERM").unwrap_or_default().to_lowercase();
+    if let Some((_, mode)) = KNOWN_TERMINALS
+        .iter()
+        .find(|(prefix, _)| term.starts_with(prefix))
+    {
+        *mode
+    } else if term.ends_with("-direct") {
+        ColorMode::TrueColor
+    } else if term.ends_with("-256color") {
+        ColorMode::Indexed256
+    } else {
+        ColorMode::Basic16
+    }
+}
+
+/// Sets the color path [`ColorMode::Auto`] resolves to, replacing environment detection.
+///
+/// Use it to apply what the application learned about the terminal, such as the result of a
+/// terminal probe with the `probe` feature. `None` returns to detection. Passing
+/// [`ColorMode::Auto`] is the same as `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Modifier;
+/// use tui_shimmer::{set_color_support, ColorMode, ShimmerConfig};
+///
+/// set_color_support(Some(ColorMode::Indexed256));
+/// let resolved = ShimmerConfig::default().resolve(Default::default());
+/// assert_eq!(resolved.color_mode, ColorMode::Indexed256);
+///
+/// let peak = ColorMode::PeakModifier(Modifier::BOLD | Modifier::REVERSED);
+/// set_color_support(Some(peak));
+/// assert_eq!(ShimmerConfig::default().resolve(Default::default()).color_mode, peak);
+/// set_color_support(None);
+/// ```
+pub fn set_color_support(mode: Option<ColorMode>) {
+    COLOR_SUPPORT_OVERRIDE.store(encode_override(mode), Ordering::Relaxed);
+}
+
+fn color_support_override() -> Option<ColorMode> {
+    decode_override(COLOR_SUPPORT_OVERRIDE.load(Ordering::Relaxed))
+}
+
+/// Packs an override into the low byte as a variant tag, with the
+/// [`ColorMode::PeakModifier`] bits above it. `None` and [`ColorMode::Auto`] are 0.
+fn encode_override(mode: Option<ColorMode>) -> u32 {
+    match mode {
+        None | Some(ColorMode::Auto) => 0,
+        Some(ColorMode::TrueColor) => 1,
+        Some(ColorMode::Indexed256) => 2,
+        Some(ColorMode::Basic16) => 3,
+        Some(ColorMode::ModifierOnly) => 4,
+        Some(ColorMode::PeakModifier(modifier)) => 5 | u32::from(modifier.bits()) << 8,
+        Some(ColorMode::Plain) => 6,
+    }
+}
+
+fn decode_override(bits: u32) -> Option<ColorMode> {
+    match bits & 0xff {
+        1 => Some(ColorMode::TrueColor),
+        2 => Some(ColorMode::Indexed256),
+        3 => Some(ColorMode::Basic16),
+        4 => Some(ColorMode::ModifierOnly),
+        5 => Some(ColorMode::PeakModifier(Modifier::from_bits_truncate(
+            (bits >> 8) as u16,
+        ))),
+        6 => Some(ColorMode::Plain),
+        _ => None,
+    }
+}
+
+/// Without the `std` feature there is no environment to inspect, so the basic tier is
+/// assumed.
+#[cfg(not(feature = "std"))]
+pub(crate) fn detected_color_mode() -> ColorMode {
+    color_support_override().unwrap_or(ColorMode::Basic16)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn detected_color_mode() -> ColorMode {
+    color_support_override().unwrap_or_else(|| {
+        *COLOR_MODE_CACHE.get_or_init(|| detect_color_mode(|name| std::env::var(name).ok()))
+    })
+}
@@ -0,0 +1,500 @@
+use std::ops::Range;
+
+use ratatui_core::style::{Color, Modifier};
+
+use crate::{
+    BandTransform, ColorMode, EffectKind, HighlightStyle, IndexedResolver, ModifierRamp,
+    ShadowConfig, ShimmerGradient, TerminalCapabilities,
+};
+
+mod builders;
+mod resolve;
+mod summary;
+
+pub use resolve::ResolvedConfig;
+
+/// Rendering options for the config-taking shimmer functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerConfig {
+    /// Color path used for the band. Defaults to [`ColorMode::Auto`].
+    pub color_mode: ColorMode,
+    /// When the color mode resolves to [`ColorMode::Plain`], blink the band's peak cells with
+    /// [`Modifier::SLOW_BLINK`] instead of rendering statically, as in
+    /// [`ColorMode::PeakModifier`].
+    ///
+    /// A last-resort motion cue for terminals where colors are switched off. Blinking is
+    /// divisive, so it is opt-in, and shimmers disabled with
+    /// [`set_shimmer_enabled`](crate::set_shimmer_enabled) stay static. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::Plain,
+    ///     blink_when_no_color: true,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let spans = shimmer_spans_with_config("Loading the index...", Style::default(), 0.5, &config);
+    /// assert!(spans.iter().any(|span| span.style.add_modifier.contains(Modifier::SLOW_BLINK)));
+    /// assert!(spans.iter().all(|span| span.style.fg.is_none()));
+    /// ```
+    pub blink_when_no_color: bool,
+    /// Terminal color support known to the application, such as from its terminal backend.
+    ///
+    /// When set, [`ColorMode::Auto`] resolves from these capabilities, ignoring
+    /// [`set_color_support`](crate::set_color_support) and the environment. An explicit
+    /// [`color_mode`](ShimmerConfig::color_mode) still wins. Defaults to `None`, which detects
+    /// globally.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_shimmer::{set_color_support, ColorMode, ShimmerConfig, TerminalCapabilities};
+    ///
+    /// set_color_support(Some(ColorMode::Basic16));
+    /// let config = ShimmerConfig {
+    ///     capabilities: Some(TerminalCapabilities { truecolor: true, colors_256: true }),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// assert_eq!(config.resolve(Default::default()).color_mode, ColorMode::TrueColor);
+    /// set_color_support(None);
+    /// ```
+    pub capabilities: Option<TerminalCapabilities>,
+    /// Half-width of the highlight band in characters. Defaults to 5.
+    ///
+    /// 0 lights only the band-center cell, at full intensity.
+    ///
+    /// ```rust
+    /// use tui_shimmer::{intensity_at_with_config, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig { band_half_width: 0, ..ShimmerConfig::default() };
+    /// for (phase, center) in [(0.3, 2), (0.5, 10), (0.52, 11)] {
+    ///     let intensities: Vec<f32> =
+    ///         (0..21).map(|index| intensity_at_with_config(index, 21, phase, &config)).collect();
+    ///     let mut expected = vec![0.0; 21];
+    ///     expected[center] = 1.0;
+    ///     assert_eq!(intensities, expected);
+    /// }
+    /// ```
+    pub band_half_width: usize,
+    /// Half-width on the side of the band facing the direction of travel, for a sharp front.
+    /// Defaults to `None`, which uses [`band_half_width`](ShimmerConfig::band_half_width).
+    ///
+    /// Capped at [`padding`](ShimmerConfig::padding) so the band still enters and leaves
+    /// the text smoothly.
+    pub leading_width: Option<usize>,
+    /// Half-width on the side of the band it leaves behind, for a long tail. Defaults to
+    /// `None`, which uses [`band_half_width`](ShimmerConfig::band_half_width).
+    ///
+    /// Capped at [`padding`](ShimmerConfig::padding) like
+    /// [`leading_width`](ShimmerConfig::leading_width).
+    pub trailing_width: Option<usize>,
+    /// Extra cells the band travels before and after the text. Defaults to 10.
+    pub padding: usize,
+    /// Char indices the band is confined to, such as the region of a progress readout.
+    ///
+    /// The band sweeps only across this range, with the padding before and after it, and
+    /// cells outside it always keep the base style. The range is clamped rather than
+    /// rejected: an end past the text is cut to the text's end, and a range that starts at or
+    /// after its end, or past the text, is empty and leaves nothing to light. Defaults to
+    /// `None`, the whole text.
+    ///
+    /// Range lists passed to functions, such as the regions of
+    /// [`shimmer_regions`](crate::shimmer_regions), are validated instead, since a bad one is
+    /// a caller bug rather than a stale setting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     active_range: Some(6..14),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+    /// let plain = shimmer_styles("Step: ======== 42%", base, 0.0, &config)[0];
+    /// for step in 0..100 {
+    ///     let styles = shimmer_styles("Step: ======== 42%", base, step as f32 / 100.0, &config);
+    ///     assert!(styles[..6].iter().chain(&styles[14..]).all(|style| *style == plain));
+    /// }
+    /// let lit = shimmer_styles("Step: ======== 42%", base, 0.5, &config);
+    /// assert!(lit[6..14].iter().any(|style| *style != plain));
+    ///
+    /// // Out-of-bounds and reversed ranges are clamped, never a panic.
+    /// let clamped = |range| ShimmerConfig { active_range: Some(range), ..config.clone() };
+    /// let past_end = shimmer_styles("Step: ======== 42%", base, 0.5, &clamped(6..100));
+    /// assert_eq!(past_end, shimmer_styles("Step: ======== 42%", base, 0.5, &clamped(6..18)));
+    /// for range in [14..6, 30..40, 100..2] {
+    ///     for step in 0..100 {
+    ///         let styles = shimmer_styles("Step: ======== 42%", base, step as f32 / 100.0, &clamped(range.clone()));
+    ///         assert!(styles.iter().all(|style| *style == plain));
+    ///     }
+    /// }
+    /// ```
+    pub active_range: Option<Range<usize>>,
+    /// Emit a single span with the unmodified base style while no cell is inside the band.
+    ///
+    /// Without this, frames where the band is in the padding still carry the resolved base
+    /// color (and `DIM` on the fallback path). Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::Basic16,
+    ///     plain_outside_band: true,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let base = Style::default().fg(Color::Cyan);
+    /// // "Loading..." sweeps 30 positions; at 0.05 the band is deep in the leading padding.
+    /// assert_eq!(
+    ///     shimmer_spans_with_config("Loading...", base, 0.05, &config),
+    ///     vec![Span::styled("Loading...", base)]
+    /// );
+    /// assert!(shimmer_spans_with_config("Loading...", base, 0.5, &config).len() > 1);
+    /// ```
+    pub plain_outside_band: bool,
+    /// Style the blank columns around the text by the band in fixed-width layouts, so the band
+    /// is seen entering and leaving through them.
+    ///
+    /// Applies to [`shimmer_line_in_width`](crate::shimmer_line_in_width), which then emits the
+    /// columns around the text as styled spaces. Only the foreground and modifiers change, so
+    /// on spaces the band shows through modifiers that are visible on blanks, such as
+    /// `REVERSED` or `UNDERLINED` from a [`ModifierRamp`]. Defaults to `false`.
+    pub render_padding: bool,
+    /// Emit a single span with the unmodified base style when the text is only whitespace.
+    ///
+    /// Without this, blank text still gets per-cell styles, which shows as a tint moving over
+    /// empty space when the styles carry a background. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     plain_whitespace: true,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let base = Style::default().bg(Color::Blue);
+    /// assert_eq!(shimmer_spans_with_config("    ", base, 0.5, &config), vec![Span::styled("    ", base)]);
+    /// ```
+    pub plain_whitespace: bool,
+    /// Apply ordered dithering when mapping blended colors to [`ColorMode::Indexed256`].
+    ///
+    /// Neighbouring lit cells alternate between the two nearest palette entries instead of
+    /// snapping to one, which hides banding; see [`ColorMode::Indexed256`]. Defaults to `false`.
+    pub dither: bool,
+    /// Step the blended colors' channels move in on the RGB paths; 0 and 1 turn it off.
+    ///
+    /// Each channel's offset from the base color is rounded to a multiple of this, so most
+    /// nearby frames, such as close [`sub_cell`](ShimmerConfig::sub_cell) positions, render
+    /// identical spans instead of differing by one step in a channel. Cells outside the band
+    /// keep the exact base color. Defaults to 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     sub_cell: true,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+    /// let render = |phase, config: &ShimmerConfig| {
+    ///     shimmer_spans_with_config("Loading the index...", base, phase, config)
+    /// };
+    /// assert_ne!(render(0.515, &config), render(0.5152, &config));
+    ///
+    /// let config = ShimmerConfig { color_quantization: 8, ..config };
+    /// assert_eq!(render(0.515, &config), render(0.5152, &config));
+    /// ```
+    pub color_quantization: u8,
+    /// Maps blended RGB colors to a palette index on the [`ColorMode::Indexed256`] path.
+    ///
+    /// `None` uses the built-in nearest match against the standard xterm color cube and gray
+    /// ramp. Supply a resolver to match a terminal whose palette is themed or remapped. A
+    /// custom resolver replaces [`dither`](ShimmerConfig::dither), which assumes the standard
+    /// cube. Defaults to `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{ColorMode, IndexedResolver, ShimmerConfig};
+    ///
+    /// // A palette where entry 1 is the only dark color and entry 15 the only light one.
+    /// fn two_tone((r, g, b): (u8, u8, u8)) -> u8 {
+    ///     if u16::from(r) + u16::from(g) + u16::from(b) > 382 { 15 } else { 1 }
+    /// }
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::Indexed256,
+    ///     indexed_resolver: Some(IndexedResolver(two_tone)),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let styles = shimmer_styles("Loading", Style::default().fg(Color::Black), 0.5, &config);
+    /// assert!(styles.iter().all(|style| matches!(style.fg, Some(Color::Indexed(1 | 15)))));
+    /// assert!(styles.iter().any(|style| style.fg == Some(Color::Indexed(15))));
+    /// ```
+    pub indexed_resolver: Option<IndexedResolver>,
+    /// Texts narrower than this many display columns render statically with the base style.
+    ///
+    /// One- and two-cell labels such as `"✓"` only blink on and off as the band passes. Wide
+    /// characters count as two columns. Defaults to 3; set to 0 to animate everything.
+    ///
+    /// ```rust
+    /// use ratatui::style::Style;
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{is_animated, shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig { color_mode: ColorMode::Basic16, ..ShimmerConfig::default() };
+    /// let render = |text| shimmer_spans_with_config(text, Style::default(), 0.5, &config);
+    /// for text in ["✓", "ok"] {
+    ///     assert!(!is_animated(text, &config));
+    ///     assert_eq!(render(text), [Span::styled(text, Style::default())]);
+    /// }
+    /// // Three columns animate, and so does a single wide emoji next to one more cell.
+    /// assert!(is_animated("...", &config));
+    /// assert!(render("...").iter().any(|span| span.style != Style::default()));
+    /// assert!(!is_animated("⏳", &config));
+    /// assert!(is_animated("⏳…", &config));
+    /// ```
+    pub min_animated_len: usize,
+    /// Modifiers applied by intensity on every color path.
+    ///
+    /// `None` keeps the built-in ramps: [`ModifierRamp::TRUE_COLOR_DEFAULT`] for the RGB and
+    /// indexed paths and [`ModifierRamp::FALLBACK_DEFAULT`] for the others.
+    pub modifier_ramp: Option<ModifierRamp>,
+    /// Minimum intensity for `BOLD` under [`ModifierRamp::TRUE_COLOR_DEFAULT`], the built-in
+    /// ramp of the RGB and indexed paths.
+    ///
+    /// At the default `0.0` every cell inside the band is bold. Raising it keeps the faint
+    /// edges regular weight, which looks cleaner and merges more edge cells into shared spans.
+    /// Ignored when [`modifier_ramp`](ShimmerConfig::modifier_ramp) is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{intensity_at_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     bold_threshold: 0.5,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let styles = shimmer_styles("Loading the index", Style::default(), 0.5, &config);
+    /// for (index, style) in styles.iter().enumerate() {
+    ///     let intensity = intensity_at_with_config(index, styles.len(), 0.5, &config);
+    ///     assert_eq!(style.add_modifier.contains(Modifier::BOLD), intensity >= 0.5);
+    /// }
+    /// // Only the center of the band is bold; its edges are lit but regular weight.
+    /// let bold = styles.iter().filter(|style| style.add_modifier.contains(Modifier::BOLD)).count();
+    /// assert_eq!(bold, 5);
+    /// ```
+    pub bold_threshold: f32,
+    /// Extra modifier, such as [`Modifier::UNDERLINED`], for the single outermost lit cell on
+    /// the side the band moves toward, to show the direction of motion.
+    ///
+    /// The band moves left to right unless a negative [`velocity`](ShimmerConfig::velocity)
+    /// reverses it, in which case the leftmost lit cell leads. Nothing is marked while the
+    /// leading edge is outside the text. Applies to [`shimmer_spans_with_config`] and the
+    /// renderers built on it. Defaults to `None`.
+    ///
+    /// [`shimmer_spans_with_config`]: crate::shimmer_spans_with_config
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let ltr = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     leading_edge_modifier: Some(Modifier::UNDERLINED),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let rtl = ltr.clone().velocity(-10.0);
+    /// let underlined = |config: &ShimmerConfig| -> Vec<usize> {
+    ///     let styles = shimmer_styles("Loading the index...", Style::default(), 0.5, config);
+    ///     (0..styles.len())
+    ///         .filter(|&index| styles[index].add_modifier.contains(Modifier::UNDERLINED))
+    ///         .collect()
+    /// };
+    ///
+    /// // The band centers on cell 10 and lights cells 6 to 14.
+    /// assert_eq!(underlined(&ltr), [14]);
+    /// assert_eq!(underlined(&rtl), [6]);
+    /// ```
+    pub leading_edge_modifier: Option<Modifier>,
+    /// Color the band blends toward on the RGB and indexed paths. `None` means white.
+    pub highlight: Option<Color>,
+    /// Share of the way the band center moves from the base toward the highlight on the RGB
+    /// and indexed paths (0.0..=1.0). Defaults to `0.9`.
+    pub max_highlight: f32,
+    /// How the peak color is derived. Defaults to [`HighlightStyle::Blend`], which uses
+    /// [`highlight`](ShimmerConfig::highlight).
+    pub highlight_style: HighlightStyle,
+    /// Derive a contrasting highlight from the base color when [`highlight`] is unset or
+    /// equals the base.
+    ///
+    /// Dark and mid-tone bases are lightened and very light bases are darkened, so a single
+    /// color input still produces a visible band. Defaults to `false`.
+    ///
+    /// [`highlight`]: ShimmerConfig::highlight
+    pub auto_highlight: bool,
+    /// Colors the band by intensity on the RGB and indexed paths instead of blending toward
+    /// [`highlight`](ShimmerConfig::highlight); resting cells keep the base. Defaults to `None`.
+    pub gradient: Option<ShimmerGradient>,
+    /// Slowly cycles the base foreground between two colors, as `(from, to, period_seconds)`,
+    /// underneath the faster band. Defaults to `None`.
+    ///
+    /// The wash needs its own slow clock, so only [`ShimmerState`](crate::ShimmerState) applies
+    /// it; the stateless span builders ignore it. It affects the RGB and indexed paths only.
+    pub base_wash: Option<(Color, Color, f32)>,
+    /// Darkening band that trails the highlight, for a subtle raised look. Defaults to `None`.
+    ///
+    /// Applied by the span builders on the RGB and indexed paths.
+    pub shadow: Option<ShadowConfig>,
+    /// Place the band at its exact fractional position instead of snapping it to whole cells.
+    ///
+    /// Cells between two band positions get intermediate intensities, so slow sweeps glide
+    /// rather than step. Costs a cosine per cell in place of a table lookup, unless
+    /// [`lut_resolution`](ShimmerConfig::lut_resolution) is set. Defaults to `false`.
+    pub sub_cell: bool,
+    /// Samples per half-width in a precomputed falloff table for the
+    /// [`sub_cell`](ShimmerConfig::sub_cell) path, interpolated linearly between samples.
+    ///
+    /// `None` evaluates the cosine for every cell. A table of 64 samples stays within 0.001 of
+    /// the cosine. Values are clamped to `2..=1024`; each distinct resolution builds its table
+    /// once per process. Defaults to `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_shimmer::{intensity_at_with_config, ShimmerConfig};
+    ///
+    /// let exact = ShimmerConfig { sub_cell: true, ..ShimmerConfig::default() };
+    /// let table = ShimmerConfig { lut_resolution: Some(64), ..exact.clone() };
+    /// for step in 0..200 {
+    ///     let phase = step as f32 / 200.0;
+    ///     for index in 0..12 {
+    ///         let direct = intensity_at_with_config(index, 12, phase, &exact);
+    ///         let sampled = intensity_at_with_config(index, 12, phase, &table);
+    ///         assert!((direct - sampled).abs() < 0.001);
+    ///     }
+    /// }
+    /// ```
+    pub lut_resolution: Option<usize>,
+    /// How much the band dims toward the ends of the text (0.0..=1.0).
+    ///
+    /// Each cell's intensity is scaled by `1 - center_emphasis * d`, where `d` runs from 0.0 at
+    /// the middle of the text to 1.0 at either end, so the highlight peaks in the middle of the
+    /// label. Defaults to `0.0` (uniform).
+    ///
+    /// ```rust
+    /// use tui_shimmer::{intensity_at_with_config, ShimmerConfig};
+    ///
+    /// // Brightest intensity each cell reaches over a whole sweep of 21 chars.
+    /// let crest = |index, center_emphasis| {
+    ///     let config = ShimmerConfig { center_emphasis, ..ShimmerConfig::default() };
+    ///     (0..400)
+    ///         .map(|step| intensity_at_with_config(index, 21, step as f32 / 400.0, &config))
+    ///         .fold(0.0, f32::max)
+    /// };
+    /// assert_eq!(crest(10, 0.0), crest(0, 0.0));
+    /// assert!(crest(10, 0.6) > crest(5, 0.6) && crest(5, 0.6) > crest(0, 0.6));
+    /// assert!((crest(0, 0.6) - 0.4 * crest(10, 0.6)).abs() < 1e-3);
+    /// ```
+    pub center_emphasis: f32,
+    /// Intensity below which a cell snaps to the base style (0.0..=1.0).
+    ///
+    /// The band's outer edges are barely visible but still need spans of their own; cutting
+    /// them off tightens the band and lowers the span count. Applied after
+    /// [`center_emphasis`](ShimmerConfig::center_emphasis). Defaults to `0.0` (off).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+    /// let cut = ShimmerConfig { dead_zone: 0.1, ..config.clone() };
+    /// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+    /// let text = "Loading the index...";
+    ///
+    /// // The band centers on cell 10; cells 6 and 14 sit at about 0.095.
+    /// let styles = shimmer_styles(text, base, 0.5, &cut);
+    /// assert_eq!(styles[6], styles[0]);
+    /// assert_eq!(styles[14], styles[0]);
+    /// assert_ne!(shimmer_styles(text, base, 0.5, &config)[6], styles[0]);
+    /// assert!(
+    ///     shimmer_spans_with_config(text, base, 0.5, &cut).len()
+    ///         < shimmer_spans_with_config(text, base, 0.5, &config).len()
+    /// );
+    /// ```
+    pub dead_zone: f32,
+    /// Whether the band lights or darkens the text. Defaults to [`EffectKind::Highlight`].
+    pub effect: EffectKind,
+    /// Color a [`EffectKind::ShadowSweep`] band darkens toward. Defaults to `None`, which uses
+    /// the base foreground.
+    pub dark: Option<Color>,
+    /// Changes the glyphs of cells inside the band, so the traveling highlight also morphs the
+    /// text. Cells whose case mapping is not a single char keep their glyph. Applied by the
+    /// span builders built on [`shimmer_spans_with_config`](crate::shimmer_spans_with_config).
+    /// Defaults to [`BandTransform::None`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Style;
+    /// use tui_shimmer::{shimmer_spans_with_config, BandTransform, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     band_half_width: 2,
+    ///     band_transform: BandTransform::Upper,
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// // At phase 0.5 the band centers on cell 5 and lights cells 4 to 6.
+    /// let spans = shimmer_spans_with_config("loading bar", Style::default(), 0.5, &config);
+    /// let content: String = spans.iter().map(|span| span.content.as_ref()).collect();
+    /// assert_eq!(content, "loadING bar");
+    /// ```
+    pub band_transform: BandTransform,
+    /// Span budget for a whole batch rendered by [`shimmer_list`](crate::shimmer_list).
+    ///
+    /// Once animating the next line would take the batch past this many spans, that line and
+    /// all after it render as a single span in the base style, bounding memory for very long
+    /// lists. Single-line builders ignore it. Defaults to `None` (unlimited).
+    pub max_total_spans: Option<usize>,
+    /// Keep animating while shimmers are globally disabled with
+    /// [`set_shimmer_enabled`](crate::set_shimmer_enabled). Defaults to `false`.
+    pub ignore_shimmer_enabled: bool,
+    /// Band speed in cells per second for [`ShimmerState`](crate::ShimmerState), negative to
+    /// sweep right to left; see [`ShimmerConfig::velocity`]. Defaults to `None`, which sweeps
+    /// left to right once per [`ShimmerState::sweep_seconds`](crate::ShimmerState::sweep_seconds).
+    pub velocity: Option<f32>,
+}
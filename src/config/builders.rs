@@ -0,0 +1,137 @@
+use super::ShimmerConfig;
+use crate::{BandTransform, ColorMode, EffectKind, HighlightStyle, ModifierRamp};
+use crate::{BAND_HALF_WIDTH, MIN_ANIMATED_LEN, SHIMMER_PADDING};
+
+/// Band half-width of [`ShimmerConfig::idle`].
+const IDLE_BAND_HALF_WIDTH: usize = 3;
+/// Peak blend of [`ShimmerConfig::idle`].
+const IDLE_MAX_HIGHLIGHT: f32 = 0.3;
+
+/// Default [`ShimmerConfig::max_highlight`].
+const DEFAULT_MAX_HIGHLIGHT: f32 = 0.9;
+
+impl Default for ShimmerConfig {
+    fn default() -> Self {
+        Self {
+            color_mode: ColorMode::Auto,
+            blink_when_no_color: false,
+            capabilities: None,
+            band_half_width: BAND_HALF_WIDTH,
+            leading_width: None,
+            trailing_width: None,
+            padding: SHIMMER_PADDING,
+            active_range: None,
+            plain_outside_band: false,
+            render_padding: false,
+            plain_whitespace: false,
+            dither: false,
+            color_quantization: 1,
+            indexed_resolver: None,
+            min_animated_len: MIN_ANIMATED_LEN,
+            modifier_ramp: None,
+            bold_threshold: 0.0,
+            leading_edge_modifier: None,
+            highlight: None,
+            max_highlight: DEFAULT_MAX_HIGHLIGHT,
+            highlight_style: HighlightStyle::Blend,
+            auto_highlight: false,
+            gradient: None,
+            base_wash: None,
+            shadow: None,
+            sub_cell: false,
+            lut_resolution: None,
+            center_emphasis: 0.0,
+            dead_zone: 0.0,
+            effect: EffectKind::Highlight,
+            dark: None,
+            band_transform: BandTransform::None,
+            max_total_spans: None,
+            ignore_shimmer_enabled: false,
+            velocity: None,
+        }
+    }
+}
+
+impl ShimmerConfig {
+    /// A slow, faint shimmer for background work that should not draw attention.
+    ///
+    /// The band is narrow, moves at most 30% of the way toward the highlight and never
+    /// adds modifiers such as `BOLD`. It is placed at sub-cell precision so the long sweep of
+    /// [`ShimmerState::idle`](crate::ShimmerState::idle) glides instead of stepping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_shimmer::testing::shimmer_styles;
+    /// use tui_shimmer::{intensity_at_with_config, ColorMode, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::idle() };
+    /// let mut state = ShimmerState::idle();
+    /// for _ in 0..600 {
+    ///     let before = state.phase();
+    ///     state.tick(Duration::from_millis(16));
+    ///     for index in 0..10 {
+    ///         let change = intensity_at_with_config(index, 10, state.phase(), &config)
+    ///             - intensity_at_with_config(index, 10, before, &config);
+    ///         assert!(change.abs() < 0.03);
+    ///     }
+    ///     let styles = shimmer_styles("Syncing...", Style::default(), state.phase(), &config);
+    ///     assert!(styles.iter().all(|style| !style.add_modifier.contains(Modifier::BOLD)));
+    /// }
+    /// ```
+    pub fn idle() -> Self {
+        Self {
+            band_half_width: IDLE_BAND_HALF_WIDTH,
+            max_highlight: IDLE_MAX_HIGHLIGHT,
+            modifier_ramp: Some(ModifierRamp::NONE),
+            sub_cell: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the band speed in cells per second, with the sign giving the direction: positive
+    /// sweeps left to right, negative right to left.
+    ///
+    /// A sweep crosses the text plus both paddings, which depends on the text length, so a
+    /// [`ShimmerState`](crate::ShimmerState) resolves the velocity into a phase when it
+    /// renders, in place of its [`sweep_seconds`](crate::ShimmerState::sweep_seconds). The
+    /// stateless phase-taking functions render the phase they are given. An asymmetric band
+    /// is mirrored when it moves right to left, so the
+    /// [`leading_width`](ShimmerConfig::leading_width) stays on the side facing the direction
+    /// of travel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::{band_geometry, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig::default().velocity(-10.0);
+    /// let text = "Indexing workspace..";
+    /// let mut state = ShimmerState::new().ignore_time_scale();
+    /// let peak = |state: &ShimmerState| {
+    ///     band_geometry(text, state.phase_for(text, &config), &config).peak_col
+    /// };
+    ///
+    /// state.tick(Duration::from_secs(1));
+    /// let before = peak(&state);
+    /// state.tick(Duration::from_secs(1));
+    /// assert_eq!(peak(&state) - before, -10.0);
+    ///
+    /// // A sharp front and a long tail: the front faces left while the band moves left.
+    /// let sharp = ShimmerConfig { leading_width: Some(1), trailing_width: Some(8), ..config.clone() };
+    /// let rtl = band_geometry(text, 0.5, &sharp);
+    /// assert_eq!((rtl.peak_col - rtl.start_col, rtl.end_col - rtl.peak_col), (1.0, 8.0));
+    /// let ltr = band_geometry(text, 0.5, &sharp.velocity(10.0));
+    /// assert_eq!((ltr.peak_col - ltr.start_col, ltr.end_col - ltr.peak_col), (8.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn velocity(mut self, cells_per_second: f32) -> Self {
+        self.velocity = Some(cells_per_second);
+        self
+    }
+}
@@ -0,0 +1,76 @@
+use ratatui_core::style::{Modifier, Style};
+
+use super::ShimmerConfig;
+use crate::color::CellPainter;
+use crate::{shimmer_enabled, ColorMode};
+
+impl ShimmerConfig {
+    /// Returns the concrete values used to render text in `base_style` with this config.
+    ///
+    /// [`ColorMode::Auto`] is resolved against the environment and
+    /// [`auto_highlight`](ShimmerConfig::auto_highlight) against the base color, which helps when
+    /// debugging a render or matching adjacent UI to the band.
+    ///
+    /// The color mode follows a fixed precedence: an explicit [`color_mode`] is used as is, and
+    /// under [`ColorMode::Auto`] the config's [`capabilities`](ShimmerConfig::capabilities)
+    /// come first, then any [`set_color_support`](crate::set_color_support) override, then
+    /// environment variables such as `CLICOLOR_FORCE`. See [`ColorMode::resolve_with`] to
+    /// check the result against a given environment.
+    ///
+    /// [`color_mode`]: ShimmerConfig::color_mode
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig { auto_highlight: true, ..ShimmerConfig::default() };
+    /// let resolved = config.resolve(Style::default().fg(Color::Rgb(250, 250, 250)));
+    /// assert_ne!(resolved.color_mode, ColorMode::Auto);
+    /// assert_eq!(resolved.highlight_rgb, (100, 100, 100));
+    /// ```
+    pub fn resolve(&self, base_style: Style) -> ResolvedConfig {
+        let painter = CellPainter::new(base_style, self);
+        ResolvedConfig {
+            color_mode: painter.mode(),
+            highlight_rgb: painter.highlight_rgb(),
+            base_rgb: painter.base_rgb(),
+        }
+    }
+
+    /// The color path to render with: [`color_mode`](ShimmerConfig::color_mode) resolved
+    /// against the environment, with the fallbacks of [`gate_mode`](ShimmerConfig::gate_mode).
+    pub(crate) fn render_mode(&self) -> ColorMode {
+        let mode = match (self.color_mode, self.capabilities) {
+            (ColorMode::Auto, Some(capabilities)) => capabilities.color_mode(),
+            (mode, _) => mode.resolve(),
+        };
+        self.gate_mode(mode)
+    }
+
+    /// Replaces an already resolved `mode` with [`ColorMode::Plain`] while shimmers are
+    /// globally disabled, unless this config ignores the switch, and otherwise a plain mode
+    /// with the [`blink_when_no_color`](ShimmerConfig::blink_when_no_color) fallback.
+    pub(crate) fn gate_mode(&self, mode: ColorMode) -> ColorMode {
+        if !self.ignore_shimmer_enabled && !shimmer_enabled() {
+            ColorMode::Plain
+        } else if mode == ColorMode::Plain && self.blink_when_no_color {
+            ColorMode::PeakModifier(Modifier::SLOW_BLINK)
+        } else {
+            mode
+        }
+    }
+}
+
+/// Rendering parameters after environment detection and automatic choices, returned by
+/// [`ShimmerConfig::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedConfig {
+    /// The color path in use; never [`ColorMode::Auto`].
+    pub color_mode: ColorMode,
+    /// The color the band blends toward at full intensity.
+    pub highlight_rgb: (u8, u8, u8),
+    /// The base foreground the band blends from; mid gray when the base style has none.
+    pub base_rgb: (u8, u8, u8),
+}
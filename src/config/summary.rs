@@ -0,0 +1,76 @@
+use super::ShimmerConfig;
+use crate::color::color_to_rgb;
+use crate::{ColorMode, EffectKind};
+
+impl ShimmerConfig {
+    /// A one-line summary of the main settings, for logs and bug reports.
+    ///
+    /// Lists the color mode, band half-width (as `leading/trailing` when they differ),
+    /// padding, sweep direction, highlight color, peak strength and effect. Colors print as
+    /// `#rrggbb`, and an unset highlight as `auto`. With a [`velocity`] the speed is listed
+    /// too. The format is meant for people and may gain fields; do not parse it.
+    ///
+    /// [`velocity`]: ShimmerConfig::velocity
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     highlight: Some(Color::White),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// assert_eq!(
+    ///     config.to_compact_string(),
+    ///     "mode=truecolor band=5 pad=10 dir=ltr hl=#ffffff max=0.90 effect=highlight",
+    /// );
+    ///
+    /// let config = ShimmerConfig { trailing_width: Some(8), ..ShimmerConfig::default() }
+    ///     .velocity(-12.5);
+    /// assert_eq!(
+    ///     config.to_compact_string(),
+    ///     "mode=auto band=5/8 pad=10 dir=rtl vel=12.5/s hl=auto max=0.90 effect=highlight",
+    /// );
+    /// ```
+    pub fn to_compact_string(&self) -> String {
+        let mode = match self.color_mode {
+            ColorMode::Auto => "auto",
+            ColorMode::TrueColor => "truecolor",
+            ColorMode::Indexed256 => "256",
+            ColorMode::Basic16 => "16",
+            ColorMode::ModifierOnly => "modifier",
+            ColorMode::PeakModifier(_) => "peak",
+            ColorMode::Plain => "plain",
+        };
+        let leading = self.leading_width.unwrap_or(self.band_half_width);
+        let trailing = self.trailing_width.unwrap_or(self.band_half_width);
+        let band = if leading == trailing {
+            leading.to_string()
+        } else {
+            format!("{leading}/{trailing}")
+        };
+        let motion = match self.velocity {
+            Some(velocity) if velocity < 0.0 => format!("dir=rtl vel={}/s", -velocity),
+            Some(velocity) => format!("dir=ltr vel={velocity}/s"),
+            None => String::from("dir=ltr"),
+        };
+        let highlight = match self.highlight {
+            None => String::from("auto"),
+            Some(color) => match color_to_rgb(color) {
+                Some((r, g, b)) => format!("#{r:02x}{g:02x}{b:02x}"),
+                None => color.to_string().to_lowercase(),
+            },
+        };
+        let effect = match self.effect {
+            EffectKind::Highlight => "highlight",
+            EffectKind::ShadowSweep => "shadow-sweep",
+        };
+        format!(
+            "mode={mode} band={band} pad={} {motion} hl={highlight} max={:.2} effect={effect}",
+            self.padding, self.max_highlight,
+        )
+    }
+}
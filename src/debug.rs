@@ -0,0 +1,68 @@
+use crate::band::{band_period, Band};
+use crate::{char_width, is_animated, ShimmerConfig};
+
+/// Markers for intensities from 0.0 to 1.0, lowest first.
+const INTENSITY_MARKERS: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// Renders `text` above a line of markers showing each cell's band intensity at `phase`.
+///
+/// Markers run from `' '` (no highlight) through `.:-=+*#%` to `'@'` (the band center). Each
+/// character gets one marker per display column, so the markers stay aligned under wide
+/// characters. Text that does not animate under `config` gets a blank marker line.
+///
+/// Meant for eyeballing band behaviour in test failures and logs rather than for display.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{shimmer_debug_string, ShimmerConfig};
+///
+/// let debug = shimmer_debug_string("Loading...", 0.5, &ShimmerConfig::default());
+/// assert_eq!(debug, "Loading...\n .-*%@%*-.");
+/// ```
+pub fn shimmer_debug_string(text: &str, phase: f32, config: &ShimmerConfig) -> String {
+    format!("{text}\n{}", intensity_markers(text, phase, config))
+}
+
+/// Renders `text` above one marker line per band position, covering a whole sweep.
+///
+/// The band visits `char_count + 2 * padding` positions per cycle, so the preview has that
+/// many marker lines, each as in [`shimmer_debug_string`]. It shows the full animation at a
+/// glance in a terminal or test output.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{shimmer_cycle_preview, ShimmerConfig};
+///
+/// let config = ShimmerConfig { padding: 2, ..ShimmerConfig::default() };
+/// let preview = shimmer_cycle_preview("Loading", &config);
+/// assert_eq!(preview.lines().count(), 1 + 7 + 2 * 2);
+/// ```
+pub fn shimmer_cycle_preview(text: &str, config: &ShimmerConfig) -> String {
+    let period = band_period(text.chars().count(), config);
+    let mut preview = String::from(text);
+    for frame in 0..period {
+        // Sample the middle of each position so float rounding cannot skip or repeat one.
+        let phase = (frame as f32 + 0.5) / period as f32;
+        preview.push('\n');
+        preview.push_str(&intensity_markers(text, phase, config));
+    }
+    preview
+}
+
+/// One marker per display column of `text`, for the band at `phase`.
+fn intensity_markers(text: &str, phase: f32, config: &ShimmerConfig) -> String {
+    let char_count = text.chars().count();
+    let animated = is_animated(text, config);
+    let band = Band::new(char_count, phase, config);
+
+    let mut markers = String::with_capacity(text.len());
+    for (index, ch) in text.chars().enumerate() {
+        let intensity = if animated { band.intensity(index) } else { 0.0 };
+        let level = (intensity.clamp(0.0, 1.0) * (INTENSITY_MARKERS.len() - 1) as f32).round();
+        let marker = INTENSITY_MARKERS[level as usize];
+        markers.extend(std::iter::repeat_n(marker, char_width(ch)));
+    }
+    markers
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::ShimmerStats;
+
+/// A readout of [`ShimmerStats`] for tuning a shimmer while it runs.
+///
+/// Renders one labelled value per row, or everything on a single row with
+/// [`single_line`](ShimmerDebug::single_line) for a status bar. Rebuild it from the stats of
+/// every frame. Rows beyond the area are dropped.
+///
+/// Requires the `debug-widget` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use tui_shimmer::{ColorMode, ShimmerDebug, ShimmerStats};
+///
+/// let stats = ShimmerStats {
+///     phase: 0.25,
+///     period: 30,
+///     peak_col: -2.0,
+///     span_count: 7,
+///     color_mode: ColorMode::TrueColor,
+///     frame_interval: Duration::from_millis(50),
+/// };
+/// let area = Rect::new(0, 0, 20, 6);
+/// let mut buf = Buffer::empty(area);
+/// ShimmerDebug::new(&stats).render(area, &mut buf);
+///
+/// let row = |y| (0..20).map(|x| buf[(x, y)].symbol()).collect::<String>();
+/// assert_eq!(row(0).trim_end(), "phase  0.250");
+/// assert_eq!(row(2).trim_end(), "peak   -2.0");
+/// assert_eq!(row(4).trim_end(), "mode   TrueColor");
+///
+/// let line = ShimmerDebug::new(&stats).line();
+/// assert_eq!(
+///     line.to_string(),
+///     "phase 0.250 | period 30 | peak -2.0 | spans 7 | TrueColor | 50ms"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShimmerDebug<'a> {
+    stats: &'a ShimmerStats,
+    style: Style,
+    single_line: bool,
+}
+
+impl<'a> ShimmerDebug<'a> {
+    /// Creates a multi-row readout of `stats`.
+    pub fn new(stats: &'a ShimmerStats) -> Self {
+        Self {
+            stats,
+            style: Style::default(),
+            single_line: false,
+        }
+    }
+
+    /// Sets the style of the readout text.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Renders every value on one row, as [`line`](ShimmerDebug::line).
+    #[must_use]
+    pub fn single_line(mut self) -> Self {
+        self.single_line = true;
+        self
+    }
+
+    /// The one-row readout, for embedding in a status bar.
+    pub fn line(&self) -> Line<'static> {
+        let stats = self.stats;
+        Line::styled(
+            format!(
+                "phase {:.3} | period {} | peak {:.1} | spans {} | {:?} | {}",
+                stats.phase,
+                stats.period,
+                stats.peak_col,
+                stats.span_count,
+                stats.color_mode,
+                format_interval(stats.frame_interval),
+            ),
+            self.style,
+        )
+    }
+
+    fn rows(&self) -> [String; 6] {
+        let stats = self.stats;
+        [
+            format!("phase  {:.3}", stats.phase),
+            format!("period {} cells", stats.period),
+            format!("peak   {:.1}", stats.peak_col),
+            format!("spans  {}", stats.span_count),
+            format!("mode   {:?}", stats.color_mode),
+            format!("frame  {}", format_interval(stats.frame_interval)),
+        ]
+    }
+}
+
+impl Widget for ShimmerDebug<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &ShimmerDebug<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        if self.single_line {
+            buf.set_line(area.x, area.y, &self.line(), area.width);
+            return;
+        }
+        for (row, text) in (area.top()..area.bottom()).zip(self.rows()) {
+            buf.set_stringn(area.x, row, text, usize::from(area.width), self.style);
+        }
+    }
+}
+
+/// Formats a frame interval, showing `-` for a band that never moves.
+fn format_interval(interval: Duration) -> String {
+    if interval == Duration::MAX {
+        "-".to_owned()
+    } else {
+        format!("{interval:?}")
+    }
+}
@@ -0,0 +1,123 @@
+use std::ops::Range;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::{coalesce_spans, Frame, ShimmerConfig};
+
+/// Changed runs of a frame: char ranges paired with the spans that replace them.
+pub type FrameDiff = Vec<(Range<usize>, Vec<Span<'static>>)>;
+
+/// Reports only the parts of a shimmer line that changed since the previous frame.
+///
+/// Each call to [`diff`](ShimmerDiffer::diff) renders the frame, compares it cell by cell with
+/// the last one, and returns the changed runs as char ranges with their new spans. Writing those
+/// spans over the previous frame reproduces the new frame exactly. The first frame, and any
+/// frame whose text differs from the previous one, is returned whole as a single run starting
+/// at 0. Call [`full_frame`](ShimmerDiffer::full_frame) after changing the style or config so
+/// the next diff is complete.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig, ShimmerDiffer};
+///
+/// fn cells(spans: &[Span]) -> Vec<(char, Style)> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|ch| (ch, span.style))).collect()
+/// }
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let text = "Resolving dependency graph...";
+/// let base = Style::default().fg(Color::Rgb(120, 120, 120));
+/// let mut differ = ShimmerDiffer::new();
+///
+/// let first = differ.diff(text, base, 0.40, &config);
+/// assert_eq!(first.len(), 1);
+/// assert_eq!(first[0].0, 0..29);
+/// let mut screen = cells(&first[0].1);
+///
+/// // Replaying every diff over the stored frame reproduces each new frame exactly,
+/// // for forward steps, jumps and wrap-arounds alike.
+/// let mut seed = 0x2545_f491_u32;
+/// for _ in 0..200 {
+///     seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+///     let phase = (seed >> 8) as f32 / (1u32 << 24) as f32;
+///     for (range, spans) in differ.diff(text, base, phase, &config) {
+///         screen.splice(range, cells(&spans));
+///     }
+///     assert_eq!(screen, cells(&shimmer_spans_with_config(text, base, phase, &config)));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ShimmerDiffer {
+    text: String,
+    styles: Vec<Style>,
+    valid: bool,
+}
+
+impl ShimmerDiffer {
+    /// Creates a differ with no previous frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets the previous frame so the next [`diff`](ShimmerDiffer::diff) returns the whole
+    /// line.
+    pub fn full_frame(&mut self) {
+        self.valid = false;
+    }
+
+    /// Renders `text` at `phase` and returns the runs that differ from the previous frame.
+    pub fn diff(
+        &mut self,
+        text: &str,
+        base_style: Style,
+        phase: f32,
+        config: &ShimmerConfig,
+    ) -> FrameDiff {
+        let chars: Vec<char> = text.chars().collect();
+        let frame = Frame::new(text, chars.len(), base_style, phase, config);
+        let styles: Vec<Style> = (0..chars.len()).map(|index| frame.style(index)).collect();
+
+        let mut changes = Vec::new();
+        if !self.valid || self.text != text {
+            if !chars.is_empty() {
+                changes.push(run(&chars, &styles, 0..chars.len()));
+            }
+        } else {
+            let mut start = None;
+            for index in 0..=chars.len() {
+                let changed = index < chars.len() && styles[index] != self.styles[index];
+                match (start, changed) {
+                    (None, true) => start = Some(index),
+                    (Some(from), false) => {
+                        changes.push(run(&chars, &styles, from..index));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.text.clear();
+        self.text.push_str(text);
+        self.styles = styles;
+        self.valid = true;
+        changes
+    }
+}
+
+fn run(
+    chars: &[char],
+    styles: &[Style],
+    range: Range<usize>,
+) -> (Range<usize>, Vec<Span<'static>>) {
+    let cells = chars[range.clone()]
+        .iter()
+        .copied()
+        .zip(styles[range.clone()].iter().copied());
+    let spans = coalesce_spans(cells, range.len());
+    (range, spans)
+}
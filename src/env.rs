@@ -0,0 +1,164 @@
+use ratatui_core::style::Color;
+
+use crate::{ColorMode, ShimmerConfig, ShimmerState};
+
+/// Sweep length in milliseconds, read by [`ShimmerState::sweep_seconds_from_env`].
+const SWEEP_MS_VAR: &str = "TUI_SHIMMER_SWEEP_MS";
+/// Band half-width in cells.
+const BAND_VAR: &str = "TUI_SHIMMER_BAND";
+/// Highlight color: a name, `#rrggbb` or `rrggbb`, or a 256-color index.
+const HIGHLIGHT_VAR: &str = "TUI_SHIMMER_HIGHLIGHT";
+/// `off`, `reduced` or `full`.
+const MODE_VAR: &str = "TUI_SHIMMER_MODE";
+
+impl ShimmerConfig {
+    /// Returns the default configuration overlaid with the `TUI_SHIMMER_*` environment
+    /// variables; see [`ShimmerConfig::overlay_env_with`].
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        config.overlay_env();
+        config
+    }
+
+    /// Overrides the fields whose `TUI_SHIMMER_*` environment variable is set to a valid value.
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn overlay_env(&mut self) {
+        self.overlay_env_with(|name| std::env::var(name).ok());
+    }
+
+    /// Overrides fields from variables looked up through `var`, so end users can tune the
+    /// shimmer of a binary without a settings UI.
+    ///
+    /// | Variable | Field | Values |
+    /// |---|---|---|
+    /// | `TUI_SHIMMER_BAND` | [`band_half_width`](ShimmerConfig::band_half_width) | cells, e.g. `3` |
+    /// | `TUI_SHIMMER_HIGHLIGHT` | [`highlight`](ShimmerConfig::highlight) | `#ffcc00`, `ffcc00`, `cyan` or `214` |
+    /// | `TUI_SHIMMER_MODE` | [`color_mode`](ShimmerConfig::color_mode) | `off` ([`ColorMode::Plain`]), `reduced` ([`ColorMode::ModifierOnly`]) or `full` ([`ColorMode::Auto`]) |
+    ///
+    /// Unset or unparsable variables leave the field as it was, so values set in code act as
+    /// defaults that the environment may override. The sweep length is set on the clock with
+    /// [`ShimmerState::sweep_seconds_from_env`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig};
+    ///
+    /// let env = |name: &str| match name {
+    ///     "TUI_SHIMMER_HIGHLIGHT" => Some("#ffcc00".to_owned()),
+    ///     "TUI_SHIMMER_BAND" => Some("wide".to_owned()),
+    ///     _ => None,
+    /// };
+    /// let mut config = ShimmerConfig { band_half_width: 3, ..ShimmerConfig::default() };
+    /// config.overlay_env_with(env);
+    /// assert_eq!(config.highlight, Some(Color::Rgb(255, 204, 0)));
+    /// assert_eq!(config.band_half_width, 3);
+    ///
+    /// // Set variables override values set in code; unset ones keep them.
+    /// let overlay = |vars: &[(&str, &str)]| {
+    ///     let mut config = ShimmerConfig {
+    ///         band_half_width: 3,
+    ///         highlight: Some(Color::Magenta),
+    ///         color_mode: ColorMode::TrueColor,
+    ///         ..ShimmerConfig::default()
+    ///     };
+    ///     config.overlay_env_with(|name| {
+    ///         vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+    ///     });
+    ///     config
+    /// };
+    /// assert_eq!(overlay(&[]).color_mode, ColorMode::TrueColor);
+    /// assert_eq!(overlay(&[("TUI_SHIMMER_BAND", " 7 ")]).band_half_width, 7);
+    /// assert_eq!(overlay(&[("TUI_SHIMMER_MODE", "off")]).color_mode, ColorMode::Plain);
+    /// assert_eq!(overlay(&[("TUI_SHIMMER_MODE", "Reduced")]).color_mode, ColorMode::ModifierOnly);
+    /// assert_eq!(overlay(&[("TUI_SHIMMER_MODE", "full")]).color_mode, ColorMode::Auto);
+    /// assert_eq!(overlay(&[("TUI_SHIMMER_MODE", "loud")]).color_mode, ColorMode::TrueColor);
+    ///
+    /// let highlight = |value| overlay(&[("TUI_SHIMMER_HIGHLIGHT", value)]).highlight;
+    /// assert_eq!(highlight("ffcc00"), Some(Color::Rgb(255, 204, 0)));
+    /// assert_eq!(highlight("cyan"), Some(Color::Cyan));
+    /// assert_eq!(highlight("214"), Some(Color::Indexed(214)));
+    /// // Six hex digits are a color, not palette index 0.
+    /// assert_eq!(highlight("000000"), Some(Color::Rgb(0, 0, 0)));
+    /// assert_eq!(highlight("#zzzzzz"), Some(Color::Magenta));
+    /// ```
+    pub fn overlay_env_with(&mut self, var: impl Fn(&str) -> Option<String>) {
+        if let Some(band) = var(BAND_VAR).and_then(|value| value.trim().parse().ok()) {
+            self.band_half_width = band;
+        }
+        if let Some(highlight) = var(HIGHLIGHT_VAR).and_then(|value| parse_color(&value)) {
+            self.highlight = Some(highlight);
+        }
+        if let Some(mode) = var(MODE_VAR).and_then(|value| parse_mode(&value)) {
+            self.color_mode = mode;
+        }
+    }
+}
+
+impl ShimmerState {
+    /// Sets the sweep length from the `TUI_SHIMMER_SWEEP_MS` environment variable, keeping the
+    /// current value when it is unset or not a positive number of milliseconds.
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn sweep_seconds_from_env(self) -> Self {
+        self.sweep_seconds_from_env_with(|name| std::env::var(name).ok())
+    }
+
+    /// Like [`ShimmerState::sweep_seconds_from_env`], looking variables up through `var`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tui_shimmer::ShimmerState;
+    ///
+    /// let half_second_phase = |value: Option<&str>| {
+    ///     let mut state = ShimmerState::new()
+    ///         .sweep_seconds(2.0)
+    ///         .ignore_time_scale()
+    ///         .sweep_seconds_from_env_with(|_| value.map(str::to_owned));
+    ///     state.tick(Duration::from_millis(500));
+    ///     state.phase()
+    /// };
+    /// assert!((half_second_phase(Some("1000")) - 0.5).abs() < 1e-4);
+    /// // Unset, zero and unparsable values keep the 2 s sweep.
+    /// for value in [None, Some("0"), Some("fast"), Some("-500")] {
+    ///     assert!((half_second_phase(value) - 0.25).abs() < 1e-4);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn sweep_seconds_from_env_with(self, var: impl Fn(&str) -> Option<String>) -> Self {
+        match var(SWEEP_MS_VAR).and_then(|value| value.trim().parse::<u32>().ok()) {
+            Some(millis) if millis > 0 => self.sweep_seconds(millis as f32 / 1000.0),
+            _ => self,
+        }
+    }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    // Six hex digits are a color even when they also read as an index, such as `000000`.
+    let color = if value.len() == 6 && value.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        format!("#{value}").parse()
+    } else {
+        value.parse()
+    };
+    color.ok().filter(|color| *color != Color::Reset)
+}
+
+fn parse_mode(value: &str) -> Option<ColorMode> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "off" => Some(ColorMode::Plain),
+        "reduced" => Some(ColorMode::ModifierOnly),
+        "full" => Some(ColorMode::Auto),
+        _ => None,
+    }
+}
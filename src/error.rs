@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Errors reported when validating shimmer configuration.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ShimmerError {
+    /// Ramp stops must have finite thresholds in strictly ascending order.
+    UnsortedStops {
+        /// Index of the first stop that is not greater than its predecessor.
+        index: usize,
+    },
+    /// Regions must lie within the text, in ascending order and without overlapping.
+    InvalidRegion {
+        /// Index of the first region that is reversed, out of bounds or overlaps its predecessor.
+        index: usize,
+    },
+    /// A gradient needs at least one stop.
+    EmptyGradient,
+    /// Gradient stop offsets must be finite and within `0.0..=1.0`.
+    InvalidGradientStop {
+        /// Index of the offending stop in the input order.
+        index: usize,
+    },
+    /// Two gradient stops share the same offset.
+    DuplicateGradientStop {
+        /// The repeated offset.
+        offset: f32,
+    },
+    /// A markup region is closed without being opened, or never closed.
+    UnbalancedMarkup {
+        /// Byte offset of the unmatched marker in the markup.
+        offset: usize,
+    },
+    /// A markup region is opened inside another.
+    NestedMarkup {
+        /// Byte offset of the inner open marker in the markup.
+        offset: usize,
+    },
+    /// Markup markers must be non-empty and distinct.
+    InvalidMarkupMarkers,
+    /// A per-cell mask must hold one weight per character.
+    MaskLength {
+        /// Number of characters in the text.
+        expected: usize,
+        /// Number of weights given.
+        actual: usize,
+    },
+    /// Pre-resolved styles must hold one style per character.
+    StyleLength {
+        /// Number of characters in the text.
+        expected: usize,
+        /// Number of styles given.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ShimmerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShimmerError::UnsortedStops { index } => write!(
+                f,
+                "stop {index} must have a finite threshold above the previous stop"
+            ),
+            ShimmerError::InvalidRegion { index } => write!(
+                f,
+                "region {index} must lie within the text after the previous region"
+            ),
+            ShimmerError::EmptyGradient => write!(f, "gradient must have at least one stop"),
+            ShimmerError::InvalidGradientStop { index } => {
+                write!(
+                    f,
+                    "gradient stop {index} must have an offset within 0.0..=1.0"
+                )
+            }
+            ShimmerError::DuplicateGradientStop { offset } => {
+                write!(f, "more than one gradient stop at offset {offset}")
+            }
+            ShimmerError::UnbalancedMarkup { offset } => {
+                write!(f, "unmatched markup marker at byte {offset}")
+            }
+            ShimmerError::NestedMarkup { offset } => {
+                write!(
+                    f,
+                    "markup region at byte {offset} opens inside another region"
+                )
+            }
+            ShimmerError::InvalidMarkupMarkers => {
+                write!(f, "markup markers must be non-empty and distinct")
+            }
+            ShimmerError::MaskLength { expected, actual } => {
+                write!(f, "mask has {actual} weights for {expected} characters")
+            }
+            ShimmerError::StyleLength { expected, actual } => {
+                write!(f, "{actual} styles given for {expected} characters")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShimmerError {}
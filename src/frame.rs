@@ -0,0 +1,142 @@
+use ratatui_core::style::{Modifier, Style};
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::table::Intensities;
+use crate::{
+    animates, build_span, char_width, coalesce_spans, moves_rtl, shimmer_display_width,
+    BandTransform, ColorMode, EffectKind, ShimmerConfig,
+};
+
+/// Per-cell styling of one text for one frame.
+// Frames are short-lived locals, so boxing the animated variant would only add an allocation.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Frame<'a> {
+    /// Every cell renders with the same style.
+    Static(Style),
+    Animated {
+        band: Intensities<'a>,
+        painter: CellPainter<'a>,
+        /// Cell and modifier of [`ShimmerConfig::leading_edge_modifier`], when on the text.
+        leading_edge: Option<(usize, Modifier)>,
+    },
+}
+
+impl<'a> Frame<'a> {
+    pub(crate) fn new(
+        text: &str,
+        char_count: usize,
+        base_style: Style,
+        phase: f32,
+        config: &'a ShimmerConfig,
+    ) -> Self {
+        let mode = config.render_mode();
+        Self::with_mode(text, char_count, base_style, phase, config, mode)
+    }
+
+    /// Like [`Frame::new`] with the color mode already resolved.
+    pub(crate) fn with_mode(
+        text: &str,
+        char_count: usize,
+        base_style: Style,
+        phase: f32,
+        config: &'a ShimmerConfig,
+        mode: ColorMode,
+    ) -> Self {
+        let band = Band::new(char_count, phase, config);
+        let leading_edge = config
+            .leading_edge_modifier
+            .and_then(|_| band.leading_edge(moves_rtl(config)));
+        Self::from_parts(
+            text,
+            base_style,
+            config,
+            mode,
+            Intensities::Band(band),
+            band.touches_text(char_count),
+            leading_edge,
+        )
+    }
+
+    pub(crate) fn from_parts(
+        text: &str,
+        base_style: Style,
+        config: &'a ShimmerConfig,
+        mode: ColorMode,
+        band: Intensities<'a>,
+        touches_text: bool,
+        leading_edge: Option<usize>,
+    ) -> Self {
+        if !animates(text, config, mode)
+            || (config.plain_outside_band
+                && config.shadow.is_none()
+                && config.effect == EffectKind::Highlight
+                && !touches_text)
+        {
+            Frame::Static(base_style)
+        } else {
+            Frame::Animated {
+                band,
+                painter: CellPainter::with_mode(base_style, config, mode),
+                leading_edge: config
+                    .leading_edge_modifier
+                    .and_then(|modifier| Some((leading_edge?, modifier))),
+            }
+        }
+    }
+
+    /// Spans for `text`, which has `char_count` chars, and its display width.
+    pub(crate) fn spans(&self, text: &str, char_count: usize) -> (Vec<Span<'static>>, usize) {
+        if let Frame::Static(style) = self {
+            return (vec![build_span(text, *style)], shimmer_display_width(text));
+        }
+
+        let mut width = 0;
+        let spans = coalesce_spans(
+            text.chars().enumerate().map(|(index, ch)| {
+                width += char_width(ch);
+                (self.glyph(index, ch), self.style(index))
+            }),
+            char_count,
+        );
+        (spans, width)
+    }
+
+    /// The glyph shown for `ch` at `index`, after [`ShimmerConfig::band_transform`].
+    pub(crate) fn glyph(&self, index: usize, ch: char) -> char {
+        match self {
+            Frame::Animated { band, painter, .. }
+                if painter.band_transform() != BandTransform::None
+                    && band.intensity(index) > 0.0 =>
+            {
+                painter.band_transform().apply(ch)
+            }
+            _ => ch,
+        }
+    }
+
+    pub(crate) fn style(&self, index: usize) -> Style {
+        match self {
+            Frame::Static(style) => *style,
+            Frame::Animated {
+                band,
+                painter,
+                leading_edge,
+            } => {
+                let style = match painter.shadow_offset() {
+                    Some(offset) => painter.style_shadowed(
+                        index,
+                        band.intensity(index),
+                        band.shadow(index, offset),
+                    ),
+                    None => painter.style(index, band.intensity(index)),
+                };
+                match leading_edge {
+                    Some((edge, modifier)) if *edge == index => style.add_modifier(*modifier),
+                    _ => style,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::{shimmer_spans_with_config, ShimmerConfig, SHIMMER_SWEEP_SECONDS};
+
+/// Returns an endless iterator of frames sampled at `fps` frames per second.
+///
+/// Frame `n` is rendered at phase `(n / (fps * 2.0)).rem_euclid(1.0)`, computed from `n` in
+/// `f64` rather than accumulated, so it matches
+/// [`shimmer_spans_with_config`] at that phase exactly, however far the iterator has run.
+/// A non-positive or non-finite `fps` renders every frame at phase 0.0. Bound the iterator
+/// with [`Iterator::take`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_frames, shimmer_spans_with_config, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let frame = shimmer_frames("Loading...", Style::default(), &config, 30.0).nth(37).unwrap();
+/// let phase = ((37.0_f64 / (30.0 * 2.0)) % 1.0) as f32;
+/// assert_eq!(frame, shimmer_spans_with_config("Loading...", Style::default(), phase, &config));
+/// ```
+pub fn shimmer_frames(
+    text: &str,
+    base_style: Style,
+    config: &ShimmerConfig,
+    fps: f32,
+) -> ShimmerFrames {
+    ShimmerFrames {
+        text: text.to_owned(),
+        base_style,
+        config: config.clone(),
+        frames_per_sweep: f64::from(fps) * f64::from(SHIMMER_SWEEP_SECONDS),
+        index: 0,
+    }
+}
+
+/// Iterator returned by [`shimmer_frames`].
+#[derive(Debug, Clone)]
+pub struct ShimmerFrames {
+    text: String,
+    base_style: Style,
+    config: ShimmerConfig,
+    frames_per_sweep: f64,
+    index: u64,
+}
+
+impl ShimmerFrames {
+    /// Skips frames identical to the one before, such as the repeats of the low-color modes.
+    ///
+    /// So that text which never changes still yields frames, a frame is repeated once a whole
+    /// sweep's worth of identical frames has been skipped.
+    pub fn dedup_consecutive(self) -> DedupFrames {
+        DedupFrames {
+            frames: self,
+            last: None,
+        }
+    }
+
+    fn phase(&self, index: u64) -> f32 {
+        if self.frames_per_sweep > 0.0 && self.frames_per_sweep.is_finite() {
+            (index as f64 / self.frames_per_sweep).rem_euclid(1.0) as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Iterator for ShimmerFrames {
+    type Item = Vec<Span<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let phase = self.phase(self.index);
+        self.index += 1;
+        Some(shimmer_spans_with_config(
+            &self.text,
+            self.base_style,
+            phase,
+            &self.config,
+        ))
+    }
+}
+
+/// Iterator returned by [`ShimmerFrames::dedup_consecutive`].
+#[derive(Debug, Clone)]
+pub struct DedupFrames {
+    frames: ShimmerFrames,
+    last: Option<Vec<Span<'static>>>,
+}
+
+impl Iterator for DedupFrames {
+    type Item = Vec<Span<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let max_skipped = self.frames.frames_per_sweep.clamp(1.0, f64::from(u32::MAX)) as u64;
+        let mut skipped = 0;
+        loop {
+            let frame = self.frames.next()?;
+            if self.last.as_ref() != Some(&frame) || skipped >= max_skipped {
+                self.last = Some(frame.clone());
+                return Some(frame);
+            }
+            skipped += 1;
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use ratatui_core::style::Color;
+
+use crate::color::{blend_rgb, color_to_rgb};
+use crate::ShimmerError;
+
+/// Colors the band by intensity instead of blending the base toward one highlight.
+///
+/// Each stop `(offset, color)` places a color at an intensity between 0.0 (band edge) and
+/// 1.0 (band center); cells between stops interpolate in RGB. Stops may be given in any order
+/// and are sorted by offset. When the first stop is above 0.0 or the last below 1.0, its color
+/// is extended to that end so every intensity is covered. Colors without an RGB value, such as
+/// [`Color::Reset`], use the base color.
+///
+/// Cells outside the band, at intensity 0.0, keep the base color rather than taking the first
+/// stop's. Put [`Color::Reset`] at 0.0 for a band that fades in from the base.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{intensity_at_with_config, ColorMode, ShimmerConfig, ShimmerError, ShimmerGradient};
+///
+/// let gradient = ShimmerGradient::new(vec![
+///     (1.0, Color::White),
+///     (0.0, Color::Blue),
+///     (0.5, Color::Cyan),
+/// ])?;
+/// let sorted = vec![(0.0, Color::Blue), (0.5, Color::Cyan), (1.0, Color::White)];
+/// assert_eq!(gradient, ShimmerGradient::new(sorted)?);
+/// assert_eq!(gradient.stops()[0], (0.0, Color::Blue));
+///
+/// assert_eq!(ShimmerGradient::new(vec![]), Err(ShimmerError::EmptyGradient));
+/// for offset in [-0.1, 1.5, f32::NAN] {
+///     assert_eq!(
+///         ShimmerGradient::new(vec![(0.0, Color::Blue), (offset, Color::White)]),
+///         Err(ShimmerError::InvalidGradientStop { index: 1 })
+///     );
+/// }
+/// assert_eq!(
+///     ShimmerGradient::new(vec![(0.5, Color::Blue), (1.0, Color::White), (0.5, Color::Cyan)]),
+///     Err(ShimmerError::DuplicateGradientStop { offset: 0.5 })
+/// );
+///
+/// // Only the band takes the gradient's colors.
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     gradient: Some(ShimmerGradient::new(vec![(0.0, Color::Blue), (1.0, Color::White)])?),
+///     ..ShimmerConfig::default()
+/// };
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// for phase in [0.0, 0.2, 0.5] {
+///     for (index, style) in shimmer_styles("Loading shimmer", base, phase, &config).iter().enumerate() {
+///         if intensity_at_with_config(index, 15, phase, &config) == 0.0 {
+///             assert_eq!(style.fg, base.fg);
+///         } else {
+///             assert_ne!(style.fg, base.fg);
+///         }
+///     }
+/// }
+/// # Ok::<(), tui_shimmer::ShimmerError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerGradient {
+    stops: Vec<(f32, Color)>,
+}
+
+impl ShimmerGradient {
+    /// Creates a gradient from stops in any order.
+    ///
+    /// Fails when no stops are given, when an offset is outside `0.0..=1.0` or not finite, or
+    /// when two stops share an offset.
+    pub fn new(mut stops: Vec<(f32, Color)>) -> Result<Self, ShimmerError> {
+        if stops.is_empty() {
+            return Err(ShimmerError::EmptyGradient);
+        }
+        if let Some(index) = stops
+            .iter()
+            .position(|(offset, _)| !(0.0..=1.0).contains(offset))
+        {
+            return Err(ShimmerError::InvalidGradientStop { index });
+        }
+
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some(pair) = stops.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(ShimmerError::DuplicateGradientStop { offset: pair[0].0 });
+        }
+
+        if let Some(&(offset, color)) = stops.first() {
+            if offset > 0.0 {
+                stops.insert(0, (0.0, color));
+            }
+        }
+        if let Some(&(offset, color)) = stops.last() {
+            if offset < 1.0 {
+                stops.push((1.0, color));
+            }
+        }
+        Ok(Self { stops })
+    }
+
+    /// The stops sorted by offset, always starting at 0.0 and ending at 1.0.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// RGB color at `intensity`, with colors lacking an RGB value replaced by `base_rgb`.
+    ///
+    /// Resting cells at intensity 0.0 keep `base_rgb`.
+    pub(crate) fn sample(&self, intensity: f32, base_rgb: (u8, u8, u8)) -> (u8, u8, u8) {
+        if intensity.is_nan() || intensity <= 0.0 {
+            return base_rgb;
+        }
+        let intensity = intensity.min(1.0);
+        let rgb = |color: Color| color_to_rgb(color).unwrap_or(base_rgb);
+        let upper = self
+            .stops
+            .iter()
+            .position(|(offset, _)| *offset >= intensity)
+            .unwrap_or(self.stops.len() - 1);
+        if upper == 0 {
+            return rgb(self.stops[0].1);
+        }
+        let (from_offset, from) = self.stops[upper - 1];
+        let (to_offset, to) = self.stops[upper];
+        let amount = (intensity - from_offset) / (to_offset - from_offset);
+        blend_rgb(rgb(to), rgb(from), amount)
+    }
+}
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{build_span, coalesce_spans, is_animated, time_scale, ShimmerConfig, ShimmerState};
+
+const DEFAULT_ATTACK: Duration = Duration::from_millis(150);
+const DEFAULT_DECAY: Duration = Duration::from_millis(400);
+
+/// A shimmer that fades in while a region is hovered and fades out after the pointer leaves.
+///
+/// The amplitude follows a linear attack/decay envelope: it rises toward 1.0 over the
+/// [`attack`](HoverShimmer::attack) time while hovered and falls toward 0.0 over the
+/// [`decay`](HoverShimmer::decay) time otherwise. A change of direction continues from the
+/// current amplitude, so rapid enter/leave events never make the effect jump.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{ColorMode, HoverShimmer, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let mut hover = HoverShimmer::new().config(config);
+/// let tick = Duration::from_millis(25);
+/// let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+/// assert_eq!(hover.spans("Open project", Style::default()).len(), 1);
+///
+/// // Enter: the 150 ms attack rises in even steps, then dwelling holds at 1.0.
+/// for step in 1..=6 {
+///     hover.update(true, tick);
+///     assert!(close(hover.style_scale(), step as f32 / 6.0));
+/// }
+/// for _ in 0..30 {
+///     hover.update(true, tick);
+///     assert_eq!(hover.style_scale(), 1.0);
+/// }
+/// assert!(hover.spans("Open project", Style::default()).len() > 1);
+///
+/// // Leave: the 400 ms decay falls back to 0.0.
+/// for step in 1..=16 {
+///     hover.update(false, tick);
+///     assert!(close(hover.style_scale(), 1.0 - step as f32 / 16.0));
+/// }
+/// assert_eq!(hover.style_scale(), 0.0);
+///
+/// // Flapping between enter and leave never jumps by more than one tick's ramp.
+/// let mut previous = hover.style_scale();
+/// for step in 0..40 {
+///     hover.update(step % 3 != 0, tick);
+///     assert!((hover.style_scale() - previous).abs() <= 1.0 / 6.0 + 1e-4);
+///     previous = hover.style_scale();
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoverShimmer {
+    state: ShimmerState,
+    attack: Duration,
+    decay: Duration,
+    config: ShimmerConfig,
+    amplitude: f32,
+}
+
+impl Default for HoverShimmer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HoverShimmer {
+    /// Creates an idle hover shimmer with a 150 ms attack and a 400 ms decay.
+    pub fn new() -> Self {
+        Self {
+            state: ShimmerState::new(),
+            attack: DEFAULT_ATTACK,
+            decay: DEFAULT_DECAY,
+            config: ShimmerConfig::default(),
+            amplitude: 0.0,
+        }
+    }
+
+    /// Sets how long the shimmer takes to fade in fully. Defaults to 150 ms.
+    #[must_use]
+    pub fn attack(mut self, attack: Duration) -> Self {
+        self.attack = attack;
+        self
+    }
+
+    /// Sets how long the shimmer takes to fade out fully. Defaults to 400 ms.
+    #[must_use]
+    pub fn decay(mut self, decay: Duration) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Sets the duration of one full sweep in seconds. Defaults to 2 seconds.
+    #[must_use]
+    pub fn sweep_seconds(mut self, sweep_seconds: f32) -> Self {
+        self.state = self.state.sweep_seconds(sweep_seconds);
+        self
+    }
+
+    /// Sets the shimmer configuration used for the band and cell styles.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Advances the band and the envelope by `dt`, scaled by the process-wide [`time_scale`].
+    ///
+    /// `hovered` is whether the pointer is over the region now.
+    pub fn update(&mut self, hovered: bool, dt: Duration) {
+        self.state.tick(dt);
+        let dt = dt.mul_f32(time_scale());
+        let (ramp, direction) = if hovered {
+            (self.attack, 1.0)
+        } else {
+            (self.decay, -1.0)
+        };
+        let step = if ramp.is_zero() {
+            1.0
+        } else {
+            dt.as_secs_f32() / ramp.as_secs_f32()
+        };
+        self.amplitude = (self.amplitude + direction * step).clamp(0.0, 1.0);
+    }
+
+    /// Current envelope amplitude (0.0..=1.0) that scales the band intensity.
+    pub fn style_scale(&self) -> f32 {
+        self.amplitude
+    }
+
+    /// Renders `text` with the band intensity scaled by the current amplitude.
+    ///
+    /// Returns a single base-style span while the amplitude is zero.
+    pub fn spans(&self, text: &str, base_style: Style) -> Vec<Span<'static>> {
+        scaled_spans(
+            text,
+            base_style,
+            self.state.phase(),
+            &self.config,
+            self.amplitude,
+        )
+    }
+}
+
+/// Renders `text` with the band intensity multiplied by `scale`, as a single base-style span
+/// while `scale` is zero.
+pub(crate) fn scaled_spans(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+    scale: f32,
+) -> Vec<Span<'static>> {
+    let char_count = text.chars().count();
+    if char_count == 0 {
+        return Vec::new();
+    }
+    if scale <= 0.0 || !is_animated(text, config) {
+        return vec![build_span(text, base_style)];
+    }
+
+    let band = Band::new(char_count, phase, config);
+    let painter = CellPainter::new(base_style, config);
+    let cells = text.chars().enumerate().map(|(index, ch)| {
+        let intensity = band.intensity(index) * scale;
+        (ch, painter.style(index, intensity))
+    });
+    coalesce_spans(cells, char_count)
+}
@@ -0,0 +1,108 @@
+use crate::IndexedResolver;
+
+pub(crate) fn indexed_to_rgb(code: u8) -> (u8, u8, u8) {
+    match code {
+        0 => (0, 0, 0),
+        1 => (170, 0, 0),
+        2 => (0, 170, 0),
+        3 => (170, 85, 0),
+        4 => (0, 0, 170),
+        5 => (170, 0, 170),
+        6 => (0, 170, 170),
+        7 => (170, 170, 170),
+        8 => (85, 85, 85),
+        9 => (255, 85, 85),
+        10 => (85, 255, 85),
+        11 => (255, 255, 85),
+        12 => (85, 85, 255),
+        13 => (255, 85, 255),
+        14 => (85, 255, 255),
+        15 => (255, 255, 255),
+        n if (16..=231).contains(&n) => {
+            let adjusted = n - 16;
+            let r = adjusted / 36;
+            let g = (adjusted % 36) / 6;
+            let b = adjusted % 6;
+            let scale = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            (scale(r), scale(g), scale(b))
+        }
+        n if n >= 232 => {
+            let gray = 8 + (n - 232) * 10;
+            (gray, gray, gray)
+        }
+        _ => (128, 128, 128),
+    }
+}
+
+/// Maps `rgb` to a palette index with `resolver`, or the built-in match when unset.
+pub(crate) fn resolve_indexed(resolver: Option<IndexedResolver>, rgb: (u8, u8, u8)) -> u8 {
+    resolver.map_or_else(|| rgb_to_indexed(rgb), |resolver| (resolver.0)(rgb))
+}
+
+pub(crate) fn rgb_to_indexed(rgb: (u8, u8, u8)) -> u8 {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let nearest_level = |value: u8| -> u8 {
+        let mut best = 0;
+        for (index, level) in CUBE_LEVELS.iter().enumerate() {
+            if value.abs_diff(*level) < value.abs_diff(CUBE_LEVELS[best]) {
+                best = index;
+            }
+        }
+        best as u8
+    };
+    let distance = |a: (u8, u8, u8), b: (u8, u8, u8)| -> u32 {
+        let dr = u32::from(a.0.abs_diff(b.0));
+        let dg = u32::from(a.1.abs_diff(b.1));
+        let db = u32::from(a.2.abs_diff(b.2));
+        dr * dr + dg * dg + db * db
+    };
+
+    let (r, g, b) = (
+        nearest_level(rgb.0),
+        nearest_level(rgb.1),
+        nearest_level(rgb.2),
+    );
+    let cube_index = 16 + 36 * r + 6 * g + b;
+
+    let average = (u16::from(rgb.0) + u16::from(rgb.1) + u16::from(rgb.2)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_index = 232 + gray_step;
+
+    if distance(rgb, indexed_to_rgb(gray_index)) < distance(rgb, indexed_to_rgb(cube_index)) {
+        gray_index
+    } else {
+        cube_index
+    }
+}
+
+/// Ordered threshold per cell, so neighbouring cells round in opposite directions.
+const DITHER_THRESHOLDS: [f32; 4] = [0.125, 0.625, 0.375, 0.875];
+
+/// Like [`rgb_to_indexed`], but rounds each value up or down between its two nearest palette
+/// steps depending on the cell index, trading long runs of one index for an alternating pattern.
+pub(crate) fn rgb_to_indexed_dithered(rgb: (u8, u8, u8), index: usize) -> u8 {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let threshold = DITHER_THRESHOLDS[index % DITHER_THRESHOLDS.len()];
+    let pick = |value: u8, lower: u8, upper: u8| -> bool {
+        upper > lower && f32::from(value - lower) / f32::from(upper - lower) > threshold
+    };
+
+    let nearest = rgb_to_indexed(rgb);
+    if nearest >= 232 {
+        let average = ((u16::from(rgb.0) + u16::from(rgb.1) + u16::from(rgb.2)) / 3) as u8;
+        let step = (average.saturating_sub(8) / 10).min(22);
+        let lower = 8 + step * 10;
+        let round_up = average >= lower && pick(average, lower, lower + 10);
+        return 232 + step + u8::from(round_up);
+    }
+
+    let channel = |value: u8| -> u8 {
+        let step = CUBE_LEVELS
+            .windows(2)
+            .position(|pair| value <= pair[1])
+            .unwrap_or(CUBE_LEVELS.len() - 2);
+        let (lower, upper) = (CUBE_LEVELS[step], CUBE_LEVELS[step + 1]);
+        step as u8 + u8::from(value >= lower && pick(value, lower, upper))
+    };
+    16 + 36 * channel(rgb.0) + 6 * channel(rgb.1) + channel(rgb.2)
+}
@@ -0,0 +1,147 @@
+use ratatui_core::style::{Color, Style};
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{ColorMode, ShimmerConfig};
+
+/// Returns the band intensity (0.0..=1.0) of the cell at `char_index` for a text of
+/// `char_count` characters, using the default band settings.
+///
+/// This is the exact value the span builders use to style each cell. With
+/// `padding = 10` and `half_width = 5`:
+///
+/// ```text
+/// period = char_count + 2 * padding
+/// pos    = floor(phase.rem_euclid(1.0) * period)
+/// dist   = |char_index + padding - pos|
+/// value  = 0.5 * (1 + cos(PI * dist / half_width))   if dist <= half_width
+///          0.0                                        otherwise
+/// ```
+///
+/// A `half_width` of 0 gives 1.0 at `dist == 0` and 0.0 everywhere else.
+///
+/// The function is pure and does not allocate.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::intensity_at;
+///
+/// // The band center sits on cell 5 of a 10-character text at phase 0.5.
+/// assert_eq!(intensity_at(5, 10, 0.5), 1.0);
+/// assert_eq!(intensity_at(0, 10, 0.0), 0.0);
+/// ```
+pub fn intensity_at(char_index: usize, char_count: usize, phase: f32) -> f32 {
+    intensity_at_with_config(char_index, char_count, phase, &ShimmerConfig::default())
+}
+
+/// Returns the band intensity of the cell at `char_index` using the band settings of `config`.
+///
+/// See [`intensity_at`] for the formula; `padding` and `half_width` come from
+/// [`ShimmerConfig::padding`] and [`ShimmerConfig::band_half_width`], split into
+/// [`ShimmerConfig::leading_width`] and [`ShimmerConfig::trailing_width`] when set.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{intensity_at_with_config, ShimmerConfig};
+///
+/// let config = ShimmerConfig {
+///     leading_width: Some(2),
+///     trailing_width: Some(8),
+///     ..ShimmerConfig::default()
+/// };
+/// // The band center sits on cell 10 of a 20-character text at phase 0.5.
+/// let lit = |range: std::ops::Range<usize>| {
+///     range
+///         .filter(|&index| intensity_at_with_config(index, 20, 0.5, &config) > 0.0)
+///         .count()
+/// };
+/// assert_eq!(lit(11..20), 1);
+/// assert_eq!(lit(0..10), 7);
+/// ```
+pub fn intensity_at_with_config(
+    char_index: usize,
+    char_count: usize,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> f32 {
+    Band::new(char_count, phase, config).intensity(char_index)
+}
+
+/// Returns the average band intensity over all `char_count` cells at `phase`.
+///
+/// A measure of how lit the text is as a whole, for adapting nearby UI, such as dimming
+/// surrounding elements while the band crosses the label. Intensities are those of
+/// [`intensity_at_with_config`], before any color is applied. Returns 0.0 for empty text.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{shimmer_mean_intensity, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// // At phase 0.0 the band sits in the padding before the text.
+/// assert_eq!(shimmer_mean_intensity(20, 0.0, &config), 0.0);
+/// assert!(shimmer_mean_intensity(20, 0.5, &config) > 0.2);
+/// ```
+pub fn shimmer_mean_intensity(char_count: usize, phase: f32, config: &ShimmerConfig) -> f32 {
+    if char_count == 0 {
+        return 0.0;
+    }
+    let band = Band::new(char_count, phase, config);
+    let total: f32 = (0..char_count).map(|index| band.intensity(index)).sum();
+    total / char_count as f32
+}
+
+/// Returns the foreground color of the brightest cell at the given phase.
+///
+/// This is the color a full render of `char_count` characters would give the band's
+/// crest, resolved through [`ShimmerConfig::color_mode`]. When the band is in the padding
+/// before or after the text, the nearest cell is used, which is usually the plain base color.
+/// Modes that keep the base foreground return `base_style.fg`, or [`Color::Reset`] when unset.
+///
+/// Useful for echoing the crest color elsewhere, such as a border that follows the band.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{shimmer_peak_color, shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let text = "Indexing workspace files";
+/// let base = Style::default().fg(Color::Rgb(90, 90, 90));
+///
+/// for phase in [0.3, 0.5, 0.7] {
+///     let peak = shimmer_peak_color(text.chars().count(), phase, base, &config);
+///     // The brightest span of the same render carries exactly this color.
+///     let brightest = shimmer_spans_with_config(text, base, phase, &config)
+///         .iter()
+///         .filter_map(|span| span.style.fg)
+///         .max_by_key(|fg| match fg {
+///             Color::Rgb(r, g, b) => u32::from(*r) + u32::from(*g) + u32::from(*b),
+///             _ => 0,
+///         });
+///     assert_eq!(Some(peak), brightest);
+/// }
+/// ```
+pub fn shimmer_peak_color(
+    char_count: usize,
+    phase: f32,
+    base_style: Style,
+    config: &ShimmerConfig,
+) -> Color {
+    let painter = CellPainter::new(base_style, config);
+    if char_count == 0 || painter.mode() == ColorMode::Plain {
+        return base_style.fg.unwrap_or(Color::Reset);
+    }
+
+    let band = Band::new(char_count, phase, config);
+    let peak_index = band.peak_index(char_count);
+
+    painter
+        .style(peak_index, band.intensity(peak_index))
+        .fg
+        .unwrap_or(Color::Reset)
+}
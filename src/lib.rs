@@ -1,46 +1,127 @@
-use std::sync::OnceLock;
-use std::time::{Duration, Instant};
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+use unicode_width::UnicodeWidthChar;
 
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Span;
+mod afterglow;
+#[cfg(feature = "asciicast")]
+mod asciicast;
+mod band;
+#[cfg(feature = "unicode-bidi")]
+mod bidi;
+mod block;
+mod chase;
+mod clock;
+mod color;
+mod color_mode;
+mod config;
+mod debug;
+#[cfg(feature = "debug-widget")]
+mod debug_widget;
+mod differ;
+mod env;
+mod error;
+mod frame;
+mod frames;
+mod gradient;
+mod hover;
+mod indexed;
+mod intensity;
+mod line;
+mod list;
+mod markup;
+mod marquee;
+mod mask;
+mod options;
+#[cfg(feature = "palette")]
+mod palette;
+mod perimeter;
+mod pool;
+#[cfg(feature = "probe")]
+mod probe;
+mod progress;
+mod ramp;
+#[cfg(feature = "record")]
+mod record;
+mod regions;
+mod render_pass;
+mod rotator;
+#[cfg(feature = "widgets")]
+mod shadowed;
+mod spinner;
+mod state;
+#[cfg(feature = "widgets")]
+mod steps;
+mod table;
+pub mod testing;
+mod track;
+mod wave;
+
+#[cfg(feature = "std")]
+use clock::shimmer_phase_from_elapsed;
+use frame::Frame;
+
+pub use afterglow::AfterglowShimmer;
+#[cfg(feature = "asciicast")]
+pub use asciicast::export_asciicast;
+pub use band::{band_geometry, frames_per_cycle, recommended_min_frame_interval, BandGeometry};
+#[cfg(feature = "unicode-bidi")]
+pub use bidi::shimmer_spans_bidi;
+pub use block::{shimmer_block, shimmer_blocks};
+pub use chase::{shimmer_chase, ChaseBand};
+#[cfg(feature = "std")]
+pub use clock::shimmer_phase_at;
+pub use clock::{
+    is_focused, set_focused, set_shimmer_enabled, set_time_scale, shimmer_enabled, time_scale,
+};
+pub use color_mode::{set_color_support, ColorMode, TerminalCapabilities};
+pub use config::{ResolvedConfig, ShimmerConfig};
+pub use debug::{shimmer_cycle_preview, shimmer_debug_string};
+#[cfg(feature = "debug-widget")]
+pub use debug_widget::ShimmerDebug;
+pub use differ::{FrameDiff, ShimmerDiffer};
+pub use error::ShimmerError;
+pub use frames::{shimmer_frames, DedupFrames, ShimmerFrames};
+pub use gradient::ShimmerGradient;
+pub use hover::HoverShimmer;
+pub use intensity::{
+    intensity_at, intensity_at_with_config, shimmer_mean_intensity, shimmer_peak_color,
+};
+pub use line::{
+    shimmer_line_aligned, shimmer_line_in_width, shimmer_rule, shimmer_rule_with_glyph,
+    shimmer_with_prefix,
+};
+pub use list::{shimmer_list, ShimmerList};
+pub use markup::{shimmer_markup, shimmer_markup_with_markers};
+pub use marquee::{shimmer_marquee, MarqueeConfig};
+pub use mask::{shimmer_spans_from_styles, shimmer_spans_masked};
+pub use options::{BandTransform, EffectKind, HighlightStyle, IndexedResolver, ShadowConfig};
+#[cfg(feature = "palette")]
+pub use palette::{blend_srgb, color_from_srgb, srgb_from_color, BlendSpace};
+pub use perimeter::{apply_perimeter_band, PerimeterCells};
+pub use pool::{ShimmerHandle, ShimmerPool};
+#[cfg(feature = "probe")]
+pub use probe::{probe_terminal_capabilities, CapabilityParser, ProbedCaps, CAPABILITY_QUERY};
+pub use progress::{progress_speed_phase, shimmer_spans_progress_speed};
+pub use ramp::ModifierRamp;
+#[cfg(feature = "record")]
+pub use record::{RecordedFrame, ShimmerRecorder, ShimmerReplayer};
+pub use regions::shimmer_regions;
+pub use render_pass::ShimmerFrame;
+pub use rotator::PhraseRotator;
+#[cfg(feature = "widgets")]
+pub use shadowed::ShimmerShadowed;
+pub use spinner::shimmer_spinner;
+pub use state::{FocusPolicy, HiddenRender, ShimmerState, ShimmerStats};
+#[cfg(feature = "widgets")]
+pub use steps::StepList;
+pub use table::ShimmerTable;
+pub use track::BandTrack;
+pub use wave::{shimmer_wave_spans, WaveConfig};
 
 const SHIMMER_PADDING: usize = 10;
 const SHIMMER_SWEEP_SECONDS: f32 = 2.0;
 const BAND_HALF_WIDTH: usize = 5;
-
-static PROCESS_START: OnceLock<Instant> = OnceLock::new();
-static TRUECOLOR_CACHE: OnceLock<bool> = OnceLock::new();
-static INTENSITY_LUT: OnceLock<Vec<f32>> = OnceLock::new();
-
-fn elapsed_since_start() -> Duration {
-    let start = PROCESS_START.get_or_init(Instant::now);
-    start.elapsed()
-}
-
-fn intensity_lut() -> &'static [f32] {
-    INTENSITY_LUT.get_or_init(|| {
-        let mut values = Vec::with_capacity(BAND_HALF_WIDTH + 1);
-        let band_half_width = BAND_HALF_WIDTH as f32;
-        for dist in 0..=BAND_HALF_WIDTH {
-            let intensity = if band_half_width > 0.0 {
-                let x = std::f32::consts::PI * (dist as f32 / band_half_width);
-                0.5 * (1.0 + x.cos())
-            } else {
-                0.0
-            };
-            values.push(intensity);
-        }
-        values
-    })
-}
-
-fn shimmer_phase_from_elapsed() -> f32 {
-    if SHIMMER_SWEEP_SECONDS <= 0.0 {
-        return 0.0;
-    }
-    let elapsed = elapsed_since_start().as_secs_f32() / SHIMMER_SWEEP_SECONDS;
-    elapsed.rem_euclid(1.0)
-}
+const MIN_ANIMATED_LEN: usize = 3;
 
 /// Creates a shimmer text effect for terminal UIs.
 ///
@@ -65,68 +146,251 @@ fn shimmer_phase_from_elapsed() -> f32 {
 ///
 /// let spans = shimmer_spans_with_style("Loading...", Style::default());
 /// ```
+///
+/// Requires the `std` feature (enabled by default).
+#[cfg(feature = "std")]
 pub fn shimmer_spans_with_style(text: &str, base_style: Style) -> Vec<Span<'static>> {
     shimmer_spans_with_style_at_phase(text, base_style, shimmer_phase_from_elapsed())
 }
 
+/// Like [`shimmer_spans_with_style`], but reads the global clock at `at` instead of now.
+///
+/// Sample the clock once per frame and pass the same instant to every call so widgets drawn
+/// later in the frame do not run slightly ahead of earlier ones. For the phase-taking helpers,
+/// such as [`shimmer_line_in_width`], pass [`shimmer_phase_at`] of the same instant.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Instant;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{
+///     shimmer_phase_at, shimmer_spans_with_style_at_instant, shimmer_spans_with_style_at_phase,
+/// };
+///
+/// let now = Instant::now();
+/// let phase = shimmer_phase_at(now);
+/// for text in ["Loading...", "Indexing files"] {
+///     assert_eq!(
+///         shimmer_spans_with_style_at_instant(text, Style::default(), now),
+///         shimmer_spans_with_style_at_phase(text, Style::default(), phase),
+///     );
+/// }
+/// ```
+///
+/// Requires the `std` feature (enabled by default).
+#[cfg(feature = "std")]
+pub fn shimmer_spans_with_style_at_instant(
+    text: &str,
+    base_style: Style,
+    at: std::time::Instant,
+) -> Vec<Span<'static>> {
+    shimmer_spans_with_style_at_phase(text, base_style, shimmer_phase_at(at))
+}
+
 /// Creates a shimmer effect at a fixed phase (0.0..1.0).
 ///
 /// This is useful for driving animation from an external frame/tick source to avoid
-/// time-based jumps under heavy CPU load.
+/// time-based jumps under heavy CPU load. It is part of the minimal
+/// `default-features = false, features = ["core"]` build.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::shimmer_spans_with_style_at_phase;
+///
+/// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+/// let spans = shimmer_spans_with_style_at_phase("Loading...", base, 0.5);
+/// assert_eq!(spans.iter().map(|span| span.content.as_ref()).collect::<String>(), "Loading...");
+/// assert_eq!(spans, shimmer_spans_with_style_at_phase("Loading...", base, 1.5));
+///
+/// // Without `std` there is no environment to detect true color from, so the band falls
+/// // back to the 16-color path.
+/// #[cfg(not(feature = "std"))]
+/// assert!(spans
+///     .iter()
+///     .all(|span| !matches!(span.style.fg, Some(Color::Rgb(..)) | Some(Color::Indexed(_)))));
+/// ```
 pub fn shimmer_spans_with_style_at_phase(
     text: &str,
     base_style: Style,
     phase: f32,
 ) -> Vec<Span<'static>> {
+    shimmer_spans_with_config(text, base_style, phase, &ShimmerConfig::default())
+}
+
+/// Creates a shimmer effect at a fixed phase (0.0..1.0) using an explicit configuration.
+///
+/// Unlike [`shimmer_spans_with_style_at_phase`], the color path is chosen by
+/// [`ShimmerConfig::color_mode`] instead of always being detected from the environment.
+///
+/// Band styles are patched onto `base_style` rather than replacing it: a cell only gets the
+/// foreground and modifiers the band needs, so the base background, underline color and
+/// other modifiers show through every cell.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     ..ShimmerConfig::default()
+/// };
+/// let base = Style::default()
+///     .fg(Color::Cyan)
+///     .bg(Color::Blue)
+///     .add_modifier(Modifier::UNDERLINED);
+/// let spans = shimmer_spans_with_config("Loading...", base, 0.5, &config);
+/// assert!(spans.iter().all(|span| span.style.bg == Some(Color::Blue)
+///     && span.style.add_modifier.contains(Modifier::UNDERLINED)));
+/// ```
+///
+/// The span API only needs `ratatui-core` types, so it builds with
+/// `default-features = false, features = ["core"]`. The `widgets` feature adds `Widget`
+/// implementations that draw the same cells:
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Style;
+/// use ratatui::text::Line;
+/// use tui_shimmer::{shimmer_spans_with_config, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let spans = shimmer_spans_with_config("Loading...", Style::default(), 0.5, &config);
+/// let area = Rect::new(0, 0, 10, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_line(0, 0, &Line::from(spans), area.width);
+/// assert_eq!(buf[(0, 0)].symbol(), "L");
+///
+/// #[cfg(feature = "widgets")]
+/// {
+///     use ratatui::widgets::Widget;
+///     use tui_shimmer::ShimmerShadowed;
+///
+///     let mut widget = Buffer::empty(area);
+///     ShimmerShadowed::new("Loading...").phase(0.5).render(area, &mut widget);
+///     assert_eq!(widget, buf);
+/// }
+/// ```
+pub fn shimmer_spans_with_config(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    shimmer_spans_with_width(text, base_style, phase, config).0
+}
+
+/// Like [`shimmer_spans_with_config`], but also returns the display width of `text`.
+///
+/// The width is accumulated while the spans are built, so layout code that centers the
+/// result does not need a second pass over the string. It always equals
+/// [`shimmer_display_width`] for the same text.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_display_width, shimmer_spans_with_width, ShimmerConfig};
+///
+/// // Wide chars take two columns and combining marks none.
+/// let text = "世界 cafe\u{301}";
+/// let (_, width) = shimmer_spans_with_width(text, Style::default(), 0.5, &ShimmerConfig::default());
+/// assert_eq!(width, 9);
+/// assert_eq!(width, shimmer_display_width(text));
+/// ```
+pub fn shimmer_spans_with_width(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> (Vec<Span<'static>>, usize) {
     let char_count = text.chars().count();
     if char_count == 0 {
-        return Vec::new();
+        return (Vec::new(), 0);
     }
 
-    let phase = phase.rem_euclid(1.0);
-    let period = char_count + SHIMMER_PADDING * 2;
-    let pos = (phase * period as f32) as isize;
+    let frame = Frame::new(text, char_count, base_style, phase, config);
+    frame.spans(text, char_count)
+}
+
+/// Whether the band sweeps right to left, which only a negative
+/// [`ShimmerConfig::velocity`] does.
+pub(crate) fn moves_rtl(config: &ShimmerConfig) -> bool {
+    config.velocity.is_some_and(|velocity| velocity < 0.0)
+}
 
-    let base_rgb = base_style
-        .fg
-        .and_then(color_to_rgb)
-        .unwrap_or((128, 128, 128));
-    let highlight_rgb = (255, 255, 255);
-    let has_true_color = supports_true_color();
-    let lut = intensity_lut();
+/// Returns the number of terminal columns `text` occupies.
+///
+/// This is the sum of the per-character widths the crate uses for every column computation;
+/// wide characters such as most emoji count as two.
+pub fn shimmer_display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
 
-    let mut spans = Vec::with_capacity(char_count);
-    let mut buffer = String::new();
-    let mut current_style: Option<Style> = None;
+fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(0)
+}
+
+/// Returns whether `text` animates under `config`.
+///
+/// Text renders as a single static span when the color mode resolves to [`ColorMode::Plain`]
+/// or shimmers are disabled with [`set_shimmer_enabled`], when it is narrower than
+/// [`ShimmerConfig::min_animated_len`] display columns, or when it is only whitespace and
+/// [`ShimmerConfig::plain_whitespace`] is set. A `false` result means the shimmer never needs a
+/// redraw for this text.
+pub fn is_animated(text: &str, config: &ShimmerConfig) -> bool {
+    animates(text, config, config.render_mode())
+}
 
-    for (index, ch) in text.chars().enumerate() {
-        let i_pos = index as isize + SHIMMER_PADDING as isize;
-        let dist = (i_pos - pos).abs() as usize;
-        let intensity = if dist <= BAND_HALF_WIDTH { lut[dist] } else { 0.0 };
+/// [`is_animated`] with the color mode already resolved.
+fn animates(text: &str, config: &ShimmerConfig, mode: ColorMode) -> bool {
+    mode != ColorMode::Plain
+        && has_min_width(text, config.min_animated_len)
+        && !(config.plain_whitespace && text.chars().all(char::is_whitespace))
+}
 
-        let style = if has_true_color {
-            let highlight = intensity.clamp(0.0, 1.0) * 0.9;
-            let (r, g, b) = blend_rgb(highlight_rgb, base_rgb, highlight);
-            // Custom RGB is intentional for shimmer.
-            #[allow(clippy::disallowed_methods)]
-            {
-                let mut style = base_style.fg(Color::Rgb(r, g, b));
-                if intensity > 0.0 {
-                    style = style.add_modifier(Modifier::BOLD);
-                }
-                style
-            }
-        } else {
-            style_for_level(intensity, base_style)
-        };
+/// Whether `text` spans at least `min` columns, stopping as soon as it does.
+fn has_min_width(text: &str, min: usize) -> bool {
+    let mut width = 0;
+    min == 0
+        || text.chars().any(|ch| {
+            width += char_width(ch);
+            width >= min
+        })
+}
+
+/// Builds an owned styled span.
+///
+/// Every span the crate emits goes through here, so a change in how ratatui constructs spans
+/// only needs adjusting in one place. `ratatui-core` 0.1 is the only supported version, and
+/// `tests/properties.rs` checks the result against `Span::styled`.
+fn build_span(content: impl Into<String>, style: Style) -> Span<'static> {
+    Span::styled(content.into(), style)
+}
 
+/// Merges consecutive cells sharing a style into single spans.
+fn coalesce_spans(
+    cells: impl IntoIterator<Item = (char, Style)>,
+    capacity: usize,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::with_capacity(capacity);
+    let mut buffer = String::new();
+    let mut current_style: Option<Style> = None;
+
+    for (ch, style) in cells {
         let same_style = current_style
             .as_ref()
             .is_some_and(|current| current == &style);
         if !same_style {
             if let Some(prev_style) = current_style.take() {
                 if !buffer.is_empty() {
-                    spans.push(Span::styled(buffer, prev_style));
+                    spans.push(build_span(buffer, prev_style));
                     buffer = String::new();
                 }
             }
@@ -137,133 +401,9 @@ pub fn shimmer_spans_with_style_at_phase(
 
     if let Some(final_style) = current_style {
         if !buffer.is_empty() {
-            spans.push(Span::styled(buffer, final_style));
+            spans.push(build_span(buffer, final_style));
         }
     }
 
     spans
 }
-
-fn supports_true_color() -> bool {
-    *TRUECOLOR_CACHE.get_or_init(|| {
-        if std::env::var_os("NO_COLOR").is_some() {
-            return false;
-        }
-
-        if std::env::var("CLICOLOR_FORCE")
-            .ok()
-            .as_deref()
-            .is_some_and(|value| value != "0")
-        {
-            return true;
-        }
-
-        if std::env::var("CLICOLOR")
-            .ok()
-            .as_deref()
-            .is_some_and(|value| value == "0")
-        {
-            return false;
-        }
-
-        std::env::var("COLORTERM")
-            .ok()
-            .map(|val| {
-                let lower = val.to_lowercase();
-                lower.contains("truecolor") || lower.contains("24bit")
-            })
-            .unwrap_or(false)
-    })
-}
-
-fn style_for_level(intensity: f32, base_style: Style) -> Style {
-    let mut style = base_style;
-    let color = if intensity < 0.2 {
-        Color::DarkGray
-    } else if intensity < 0.6 {
-        Color::Gray
-    } else {
-        Color::White
-    };
-    style = style.fg(color);
-    if intensity < 0.2 {
-        style.add_modifier(Modifier::DIM)
-    } else if intensity < 0.6 {
-        style
-    } else {
-        style.add_modifier(Modifier::BOLD)
-    }
-}
-
-fn blend_rgb(highlight: (u8, u8, u8), base: (u8, u8, u8), amount: f32) -> (u8, u8, u8) {
-    let amount = amount.clamp(0.0, 1.0);
-    let blend = |from: u8, to: u8| -> u8 {
-        let from = from as f32;
-        let to = to as f32;
-        (from + (to - from) * amount).round().clamp(0.0, 255.0) as u8
-    };
-
-    (
-        blend(base.0, highlight.0),
-        blend(base.1, highlight.1),
-        blend(base.2, highlight.2),
-    )
-}
-
-fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
-    match color {
-        Color::Rgb(r, g, b) => Some((r, g, b)),
-        Color::Black => Some((0, 0, 0)),
-        Color::Red => Some((170, 0, 0)),
-        Color::Green => Some((0, 170, 0)),
-        Color::Yellow => Some((170, 85, 0)),
-        Color::Blue => Some((0, 0, 170)),
-        Color::Magenta => Some((170, 0, 170)),
-        Color::Cyan => Some((0, 170, 170)),
-        Color::Gray => Some((170, 170, 170)),
-        Color::DarkGray => Some((85, 85, 85)),
-        Color::LightRed => Some((255, 85, 85)),
-        Color::LightGreen => Some((85, 255, 85)),
-        Color::LightYellow => Some((255, 255, 85)),
-        Color::LightBlue => Some((85, 85, 255)),
-        Color::LightMagenta => Some((255, 85, 255)),
-        Color::LightCyan => Some((85, 255, 255)),
-        Color::White => Some((255, 255, 255)),
-        Color::Indexed(code) => Some(indexed_to_rgb(code)),
-        Color::Reset => None,
-    }
-}
-
-fn indexed_to_rgb(code: u8) -> (u8, u8, u8) {
-    match code {
-        0 => (0, 0, 0),
-        1 => (170, 0, 0),
-        2 => (0, 170, 0),
-        3 => (170, 85, 0),
-        4 => (0, 0, 170),
-        5 => (170, 0, 170),
-        6 => (0, 170, 170),
-        7 => (170, 170, 170),
-        8 => (85, 85, 85),
-        9 => (255, 85, 85),
-        10 => (85, 255, 85),
-        11 => (255, 255, 85),
-        12 => (85, 85, 255),
-        13 => (255, 85, 255),
-        14 => (85, 255, 255),
-        15 => (255, 255, 255),
-        n if (16..=231).contains(&n) => {
-            let adjusted = n - 16;
-            let r = adjusted / 36;
-            let g = (adjusted % 36) / 6;
-            let b = adjusted % 6;
-            let scale = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
-            (scale(r), scale(g), scale(b))
-        }
-        n if n >= 232 => {
-            let gray = 8 + (n - 232) * 10;
-            (gray, gray, gray)
-        }
-        _ => (128, 128, 128),
-    }
-}
@@ -0,0 +1,225 @@
+use ratatui_core::layout::Alignment;
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{
+    build_span, char_width, coalesce_spans, is_animated, shimmer_display_width,
+    shimmer_spans_with_config, ShimmerConfig,
+};
+
+/// Default glyph for [`shimmer_rule`].
+const RULE_GLYPH: char = '─';
+
+/// Renders `text` as a [`Line`] with `alignment` set.
+///
+/// The spans are those of [`shimmer_spans_with_config`]; the band sweeps the text itself, so
+/// its timing does not depend on where the line is placed. Use [`shimmer_line_in_width`] to
+/// sweep the whole render width instead.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Alignment;
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_line_aligned, shimmer_spans_with_config, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let line = shimmer_line_aligned("Loading...", Style::default(), 0.5, Alignment::Center, &config);
+///
+/// assert_eq!(line.alignment, Some(Alignment::Center));
+/// assert_eq!(
+///     line.spans,
+///     shimmer_spans_with_config("Loading...", Style::default(), 0.5, &config),
+/// );
+/// ```
+pub fn shimmer_line_aligned(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    alignment: Alignment,
+    config: &ShimmerConfig,
+) -> Line<'static> {
+    Line::from(shimmer_spans_with_config(text, base_style, phase, config)).alignment(alignment)
+}
+
+/// Renders `text` as an aligned line whose band sweeps across the whole `render_width`.
+///
+/// The band travels over screen columns rather than over the text, so on a wide area it
+/// crosses empty space and only lights the text while passing over it, like a web skeleton
+/// shine. `alignment` must match how the line is laid out; it is also set on the returned
+/// [`Line`]. Use [`band_geometry`](crate::band_geometry) over `render_width` spaces to get the
+/// band position in the same screen columns.
+///
+/// With [`ShimmerConfig::render_padding`] the empty columns around the text are emitted as
+/// spaces styled by the band too, so the line fills `render_width` and the band stays visible
+/// while crossing them.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Alignment;
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{band_geometry, shimmer_line_in_width, ColorMode, ShimmerConfig};
+///
+/// // "Loading..." centered in 40 columns sits on columns 15..25.
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let gray = Style::default().fg(Color::Rgb(100, 100, 100));
+/// let lit = |phase| {
+///     shimmer_line_in_width("Loading...", gray, phase, Alignment::Center, 40, &config)
+///         .spans
+///         .iter()
+///         .any(|span| span.style != gray)
+/// };
+/// let peak = |phase| band_geometry(&" ".repeat(40), phase, &config).peak_col;
+/// // The band crosses the empty left side, the text, then the empty right side.
+/// assert_eq!((peak(0.25), lit(0.25)), (5.0, false));
+/// assert_eq!((peak(0.5), lit(0.5)), (20.0, true));
+/// assert_eq!((peak(0.75), lit(0.75)), (35.0, false));
+///
+/// // With padding rendered the line fills the width: 15 blank columns on each side.
+/// let padded = ShimmerConfig { render_padding: true, ..config.clone() };
+/// let line = shimmer_line_in_width("Loading...", gray, 0.5, Alignment::Center, 40, &padded);
+/// let content: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+/// assert_eq!(content, format!("{0}Loading...{0}", " ".repeat(15)));
+///
+/// // With padding rendered, the blank columns the band is crossing are tinted.
+/// let base = Style::default().fg(Color::Rgb(0, 0, 0));
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     render_padding: true,
+///     ..ShimmerConfig::default()
+/// };
+/// let line = shimmer_line_in_width("Hey", base, 0.5, Alignment::Left, 20, &config);
+/// assert_eq!(line.width(), 20);
+/// let styles: Vec<Style> = line
+///     .spans
+///     .iter()
+///     .flat_map(|span| span.content.chars().map(move |_| span.style))
+///     .collect();
+/// assert_ne!(styles[10], base);
+/// assert_eq!(styles[19], base);
+/// ```
+pub fn shimmer_line_in_width(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    alignment: Alignment,
+    render_width: usize,
+    config: &ShimmerConfig,
+) -> Line<'static> {
+    let text_width = shimmer_display_width(text);
+    let spans = if !is_animated(text, config) {
+        vec![build_span(text, base_style)]
+    } else {
+        let slack = render_width.saturating_sub(text_width);
+        let offset = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => slack / 2,
+            Alignment::Right => slack,
+        };
+        let band = Band::new(render_width.max(text_width), phase, config);
+        let painter = CellPainter::new(base_style, config);
+        let blank = |columns: std::ops::Range<usize>| {
+            let columns = if config.render_padding { columns } else { 0..0 };
+            columns.map(|column| (' ', painter.style(column, band.intensity(column))))
+        };
+        let mut column = offset;
+        let cells = text.chars().map(|ch| {
+            let cell = painter.style(column, band.intensity(column));
+            column += char_width(ch);
+            (ch, cell)
+        });
+        let cells = blank(0..offset)
+            .chain(cells)
+            .chain(blank(offset + text_width..render_width));
+        coalesce_spans(cells, text_width)
+    };
+    Line::from(spans).alignment(alignment)
+}
+
+/// Renders a horizontal rule of `width` `─` glyphs with the band sweeping across it.
+///
+/// Useful for animated borders and tab-bar indicators. See [`shimmer_rule_with_glyph`] to use
+/// a different glyph.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{shimmer_rule, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(80, 80, 80));
+/// let cells: Vec<(char, Option<Color>)> = shimmer_rule(24, base, 0.5, &config)
+///     .iter()
+///     .flat_map(|span| span.content.chars().map(|ch| (ch, span.style.fg)))
+///     .collect();
+///
+/// assert_eq!(cells.len(), 24);
+/// assert!(cells.iter().all(|(ch, _)| *ch == '─'));
+/// // The band lights the middle of the rule and leaves its ends at the base color.
+/// assert_eq!(cells[0].1, base.fg);
+/// assert!(matches!(cells[12].1, Some(Color::Rgb(r, _, _)) if r > 80));
+/// ```
+pub fn shimmer_rule(
+    width: usize,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    shimmer_rule_with_glyph(RULE_GLYPH, width, base_style, phase, config)
+}
+
+/// Renders `width` copies of `glyph` with the band sweeping across them.
+pub fn shimmer_rule_with_glyph(
+    glyph: char,
+    width: usize,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    let rule: String = std::iter::repeat_n(glyph, width).collect();
+    shimmer_spans_with_config(&rule, base_style, phase, config)
+}
+
+/// Renders a static `prefix`, such as an icon, followed by shimmering `text`.
+///
+/// The prefix is emitted verbatim as its own span and never takes part in the band, which is
+/// computed over `text` alone so the sweep timing matches an unprefixed label. Wide prefix
+/// glyphs keep their width since the prefix content is untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::{Line, Span};
+/// use tui_shimmer::{shimmer_spans_with_config, shimmer_with_prefix, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let prefix = Span::styled("⏳ ", Style::default().fg(Color::Yellow));
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// for step in 0..=40 {
+///     let phase = step as f32 / 40.0;
+///     let spans = shimmer_with_prefix(prefix.clone(), "Generating summary", base, phase, &config);
+///     // The prefix never changes, and the band over the text matches an unprefixed label.
+///     assert_eq!(spans[0], prefix);
+///     assert_eq!(spans[1..], shimmer_spans_with_config("Generating summary", base, phase, &config));
+///     // The wide hourglass keeps its two columns.
+///     assert_eq!(Line::from(spans).width(), 2 + 1 + "Generating summary".len());
+/// }
+/// ```
+pub fn shimmer_with_prefix<'a>(
+    prefix: Span<'a>,
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Span<'a>> {
+    let spans = shimmer_spans_with_config(text, base_style, phase, config);
+    let mut line = Vec::with_capacity(spans.len() + 1);
+    line.push(prefix);
+    line.extend(spans);
+    line
+}
@@ -0,0 +1,67 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Line;
+
+use crate::{build_span, is_animated, shimmer_spans_with_config, ShimmerConfig};
+
+/// Lines rendered by [`shimmer_list`], with how many of them animate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerList {
+    /// One line per input text, in order.
+    pub lines: Vec<Line<'static>>,
+    /// Lines rendered with the band.
+    pub animated: usize,
+    /// Lines rendered as a single base-style span, either because they do not animate under
+    /// the config or because the span budget ran out.
+    pub static_lines: usize,
+}
+
+/// Renders a batch of texts at the same phase, within [`ShimmerConfig::max_total_spans`].
+///
+/// Lines animate in order until the next one would exceed the budget; it and every later
+/// line then render statically in `base_style`. Use it for large lists, such as a log viewer
+/// with shimmering new entries, where span vectors would otherwise add up.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_list, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     max_total_spans: Some(20),
+///     ..ShimmerConfig::default()
+/// };
+/// let entries = ["first entry", "second entry", "third entry", "fourth entry"];
+/// let list = shimmer_list(entries, Style::default(), 0.5, &config);
+/// assert_eq!((list.animated, list.static_lines), (1, 3));
+/// assert_eq!(list.lines[1].spans.len(), 1);
+/// ```
+pub fn shimmer_list<'t>(
+    texts: impl IntoIterator<Item = &'t str>,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> ShimmerList {
+    let mut budget = config.max_total_spans.unwrap_or(usize::MAX);
+    let mut list = ShimmerList {
+        lines: Vec::new(),
+        animated: 0,
+        static_lines: 0,
+    };
+    for text in texts {
+        if budget > 0 && is_animated(text, config) {
+            let spans = shimmer_spans_with_config(text, base_style, phase, config);
+            if spans.len() <= budget {
+                budget -= spans.len();
+                list.animated += 1;
+                list.lines.push(Line::from(spans));
+                continue;
+            }
+            budget = 0;
+        }
+        list.static_lines += 1;
+        list.lines.push(Line::from(build_span(text, base_style)));
+    }
+    list
+}
@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Line;
+
+use crate::{shimmer_regions, ShimmerConfig, ShimmerError};
+
+/// Markers used by [`shimmer_markup`].
+const DEFAULT_MARKERS: (&str, &str) = ("{shimmer}", "{/shimmer}");
+
+/// Renders `markup`, shimmering only the text between `{shimmer}` and `{/shimmer}`.
+///
+/// Text outside the markers keeps `base_style`; each marked region runs its own band, as in
+/// [`shimmer_regions`]. A backslash makes the next character literal, so `\{shimmer}` renders
+/// as text and `\\` as a single backslash. See [`shimmer_markup_with_markers`] to use other
+/// markers.
+///
+/// Markers that are not closed or closed without being opened return
+/// [`ShimmerError::UnbalancedMarkup`]; a region opened inside another returns
+/// [`ShimmerError::NestedMarkup`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_markup, ShimmerConfig, ShimmerError};
+///
+/// let line = shimmer_markup(
+///     "Indexing {shimmer}12,431 files{/shimmer} in workspace",
+///     Style::default(),
+///     0.5,
+///     &ShimmerConfig::default(),
+/// )?;
+/// assert_eq!(line.to_string(), "Indexing 12,431 files in workspace");
+///
+/// let render = |markup| shimmer_markup(markup, Style::default(), 0.5, &ShimmerConfig::default());
+/// // Escaped markers and backslashes render literally.
+/// let escaped = render(r"Type \{shimmer} to \\animate")?;
+/// assert_eq!(escaped.to_string(), r"Type {shimmer} to \animate");
+/// // Malformed markup reports the byte offset of the offending marker.
+/// assert_eq!(render("Saving {shimmer}draft"), Err(ShimmerError::UnbalancedMarkup { offset: 7 }));
+/// assert_eq!(render("Saving{/shimmer} draft"), Err(ShimmerError::UnbalancedMarkup { offset: 6 }));
+/// assert_eq!(
+///     render("{shimmer}a {shimmer}b{/shimmer}{/shimmer}"),
+///     Err(ShimmerError::NestedMarkup { offset: 11 }),
+/// );
+/// # Ok::<(), ShimmerError>(())
+/// ```
+pub fn shimmer_markup(
+    markup: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Result<Line<'static>, ShimmerError> {
+    shimmer_markup_with_markers(markup, DEFAULT_MARKERS, base_style, phase, config)
+}
+
+/// Like [`shimmer_markup`], with custom `(open, close)` markers.
+///
+/// Use this when the text may contain the default markers. The markers must be non-empty and
+/// differ from each other, otherwise [`ShimmerError::InvalidMarkupMarkers`] is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_markup_with_markers, ShimmerConfig};
+///
+/// let line = shimmer_markup_with_markers(
+///     "Building <<{crate}>>",
+///     ("<<", ">>"),
+///     Style::default(),
+///     0.5,
+///     &ShimmerConfig::default(),
+/// )?;
+/// assert_eq!(line.to_string(), "Building {crate}");
+/// # Ok::<(), tui_shimmer::ShimmerError>(())
+/// ```
+pub fn shimmer_markup_with_markers(
+    markup: &str,
+    markers: (&str, &str),
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Result<Line<'static>, ShimmerError> {
+    let (text, regions) = parse_markup(markup, markers)?;
+    shimmer_regions(&text, base_style, phase, &regions, 0.0, config)
+}
+
+/// Plain text and the char ranges to animate, paired as [`shimmer_regions`] expects.
+type ParsedMarkup = (String, Vec<(Range<usize>, bool)>);
+
+/// Splits `markup` into its text and marked regions.
+fn parse_markup(markup: &str, (open, close): (&str, &str)) -> Result<ParsedMarkup, ShimmerError> {
+    if open.is_empty() || close.is_empty() || open == close {
+        return Err(ShimmerError::InvalidMarkupMarkers);
+    }
+
+    let mut text = String::with_capacity(markup.len());
+    let mut char_count = 0;
+    let mut regions = Vec::new();
+    // Byte offset of the open marker and the char index where its region starts.
+    let mut open_region: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    while let Some(rest) = markup.get(offset..).filter(|rest| !rest.is_empty()) {
+        if let Some(escaped) = rest.strip_prefix('\\') {
+            let ch = escaped.chars().next().unwrap_or('\\');
+            text.push(ch);
+            char_count += 1;
+            offset += 1 + escaped.chars().next().map_or(0, char::len_utf8);
+        } else if rest.starts_with(open) {
+            if open_region.is_some() {
+                return Err(ShimmerError::NestedMarkup { offset });
+            }
+            open_region = Some((offset, char_count));
+            offset += open.len();
+        } else if rest.starts_with(close) {
+            let Some((_, start)) = open_region.take() else {
+                return Err(ShimmerError::UnbalancedMarkup { offset });
+            };
+            regions.push((start..char_count, true));
+            offset += close.len();
+        } else {
+            let ch = rest.chars().next().unwrap_or_default();
+            text.push(ch);
+            char_count += 1;
+            offset += ch.len_utf8();
+        }
+    }
+
+    match open_region {
+        Some((offset, _)) => Err(ShimmerError::UnbalancedMarkup { offset }),
+        None => Ok((text, regions)),
+    }
+}
@@ -0,0 +1,117 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::{char_width, coalesce_spans, Frame, ShimmerConfig};
+
+/// Settings for [`shimmer_marquee`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarqueeConfig {
+    /// Text inserted where the loop wraps back to the start. Defaults to `"  •  "`.
+    pub separator: String,
+    /// Whether the band sweeps over the separator too. When `false` the separator keeps the
+    /// base style and the band covers the text alone. Defaults to `true`.
+    pub shimmer_separator: bool,
+    /// Band settings.
+    pub shimmer: ShimmerConfig,
+}
+
+impl Default for MarqueeConfig {
+    fn default() -> Self {
+        Self {
+            separator: String::from("  •  "),
+            shimmer_separator: true,
+            shimmer: ShimmerConfig::default(),
+        }
+    }
+}
+
+/// Renders a `width`-column window onto `text` looping endlessly, scrolled by `scroll` columns.
+///
+/// The loop is `text` followed by [`MarqueeConfig::separator`], and `scroll` wraps at its
+/// display width, so incrementing `scroll` each step gives a ticker. The band sweeps the loop
+/// at `phase` and moves with the text. A wide glyph cut by either edge of the window is
+/// replaced by spaces, so the result is always exactly `width` columns.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_marquee, MarqueeConfig};
+///
+/// let config = MarqueeConfig::default();
+/// let window = |scroll| -> String {
+///     shimmer_marquee("Deploying", Style::default(), 0.5, scroll, 14, &config)
+///         .iter()
+///         .map(|span| span.content.as_ref())
+///         .collect()
+/// };
+///
+/// assert_eq!(window(0), "Deploying  •  ");
+/// assert_eq!(window(4), "oying  •  Depl");
+/// // The window is as wide as one loop, so the separator shows once wherever it scrolls.
+/// for scroll in 0..28 {
+///     assert_eq!(window(scroll).matches('•').count(), 1);
+/// }
+/// ```
+pub fn shimmer_marquee(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    scroll: usize,
+    width: usize,
+    config: &MarqueeConfig,
+) -> Vec<Span<'static>> {
+    let text_count = text.chars().count();
+    let loop_width: usize = text
+        .chars()
+        .chain(config.separator.chars())
+        .map(char_width)
+        .sum();
+    if text_count == 0 || width == 0 || loop_width == 0 {
+        return Vec::new();
+    }
+
+    let band_text = if config.shimmer_separator {
+        format!("{text}{}", config.separator)
+    } else {
+        text.to_owned()
+    };
+    let band_count = band_text.chars().count();
+    let frame = Frame::new(&band_text, band_count, base_style, phase, &config.shimmer);
+    // Each loop cell with its index in the band, if it takes part in it.
+    let cells: Vec<(char, Option<usize>)> =
+        text.chars()
+            .enumerate()
+            .map(|(index, ch)| (ch, Some(index)))
+            .chain(
+                config.separator.chars().enumerate().map(|(index, ch)| {
+                    (ch, config.shimmer_separator.then_some(text_count + index))
+                }),
+            )
+            .collect();
+
+    let start = scroll % loop_width;
+    let end = start + width;
+    let mut column = 0;
+    let mut visible = Vec::with_capacity(width);
+    for &(ch, band_index) in cells.iter().cycle() {
+        let from = column;
+        column += char_width(ch);
+        if column <= start {
+            continue;
+        }
+        if from >= end {
+            break;
+        }
+        if from < start || column > end {
+            let shown = column.min(end) - from.max(start);
+            visible.extend(std::iter::repeat_n((' ', base_style), shown));
+            continue;
+        }
+        visible.push(match band_index {
+            Some(index) => (frame.glyph(index, ch), frame.style(index)),
+            None => (ch, base_style),
+        });
+    }
+    coalesce_spans(visible, band_count)
+}
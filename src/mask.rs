@@ -0,0 +1,114 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::{build_span, coalesce_spans, Frame, ShimmerConfig, ShimmerError};
+
+/// Renders `text` with the band intensity of each cell multiplied by its entry in `weights`.
+///
+/// Use it for data-driven effects, such as lighting only the cells that changed. Cells with a
+/// weight of 0.0 (or below) keep `base_style` whatever the band does; weights above 1.0 are
+/// treated as 1.0. `weights` must hold one value per char, otherwise
+/// [`ShimmerError::MaskLength`] is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{
+///     shimmer_spans_masked, shimmer_spans_with_config, ColorMode, ShimmerConfig, ShimmerError,
+/// };
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+/// let styles = |spans: &[ratatui::text::Span]| -> Vec<Style> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|_| span.style)).collect()
+/// };
+///
+/// // With a [1, 0, 1, 0, ...] mask only the even cells shimmer; odd cells keep the base style.
+/// let weights: Vec<f32> = (0..12).map(|index| if index % 2 == 0 { 1.0 } else { 0.0 }).collect();
+/// let masked = styles(&shimmer_spans_masked("v1.4.2-beta3", base, &weights, 0.5, &config)?);
+/// let full = styles(&shimmer_spans_with_config("v1.4.2-beta3", base, 0.5, &config));
+/// for (index, style) in masked.iter().enumerate() {
+///     assert_eq!(*style, if index % 2 == 0 { full[index] } else { base });
+/// }
+/// assert!(masked.iter().any(|style| *style != base));
+///
+/// let short = shimmer_spans_masked("v1.4.2", base, &[1.0; 4], 0.5, &config);
+/// assert_eq!(short, Err(ShimmerError::MaskLength { expected: 6, actual: 4 }));
+/// # Ok::<(), ShimmerError>(())
+/// ```
+pub fn shimmer_spans_masked(
+    text: &str,
+    base_style: Style,
+    weights: &[f32],
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Result<Vec<Span<'static>>, ShimmerError> {
+    let char_count = text.chars().count();
+    if weights.len() != char_count {
+        return Err(ShimmerError::MaskLength {
+            expected: char_count,
+            actual: weights.len(),
+        });
+    }
+    if char_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let Frame::Animated { band, painter, .. } =
+        Frame::new(text, char_count, base_style, phase, config)
+    else {
+        return Ok(vec![build_span(text, base_style)]);
+    };
+    let cells = text
+        .chars()
+        .zip(weights)
+        .enumerate()
+        .map(|(index, (ch, &weight))| {
+            let style = if weight > 0.0 {
+                painter.style(index, band.intensity(index) * weight.min(1.0))
+            } else {
+                base_style
+            };
+            (ch, style)
+        });
+    Ok(coalesce_spans(cells, char_count))
+}
+
+/// Renders `text` with the given style for each char, merging equal neighbors into one span.
+///
+/// An escape hatch for styling computed outside the crate, shimmer or not, that still wants
+/// the same span coalescing as the built-in effects. `styles` must hold one style per char,
+/// otherwise [`ShimmerError::StyleLength`] is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::shimmer_spans_from_styles;
+///
+/// let red = Style::default().fg(Color::Red);
+/// let blue = Style::default().fg(Color::Blue);
+/// let spans = shimmer_spans_from_styles("abcd", &[red, red, blue, red])?;
+/// assert_eq!(spans.len(), 3);
+/// assert_eq!(spans[0].content, "ab");
+/// let content: String = spans.iter().map(|span| span.content.as_ref()).collect();
+/// assert_eq!(content, "abcd");
+/// # Ok::<(), tui_shimmer::ShimmerError>(())
+/// ```
+pub fn shimmer_spans_from_styles(
+    text: &str,
+    styles: &[Style],
+) -> Result<Vec<Span<'static>>, ShimmerError> {
+    let char_count = text.chars().count();
+    if styles.len() != char_count {
+        return Err(ShimmerError::StyleLength {
+            expected: char_count,
+            actual: styles.len(),
+        });
+    }
+    Ok(coalesce_spans(
+        text.chars().zip(styles.iter().copied()),
+        char_count,
+    ))
+}
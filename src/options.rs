@@ -0,0 +1,169 @@
+/// How the band's peak color is chosen on the RGB and indexed paths.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HighlightStyle {
+    /// Blend toward [`ShimmerConfig::highlight`], or white when unset.
+    ///
+    /// [`ShimmerConfig::highlight`]: crate::ShimmerConfig::highlight
+    #[default]
+    Blend,
+    /// Raise the base color's HSL lightness by the given amount (0.0..=1.0), keeping its hue
+    /// and saturation, so a blue label shimmers toward a lighter blue rather than white.
+    ///
+    /// Takes precedence over [`ShimmerConfig::highlight`] and
+    /// [`ShimmerConfig::auto_highlight`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::{HighlightStyle, ShimmerConfig};
+    ///
+    /// let config = ShimmerConfig {
+    ///     highlight_style: HighlightStyle::Lighten(0.3),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let resolved = config.resolve(Style::default().fg(Color::Rgb(0, 0, 170)));
+    /// // A lighter blue, not white.
+    /// assert_eq!(resolved.highlight_rgb, (68, 68, 255));
+    /// ```
+    ///
+    /// [`ShimmerConfig::highlight`]: crate::ShimmerConfig::highlight
+    /// [`ShimmerConfig::auto_highlight`]: crate::ShimmerConfig::auto_highlight
+    Lighten(f32),
+}
+
+/// How the band changes the glyphs of the cells it passes over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BandTransform {
+    /// Glyphs are left as they are.
+    #[default]
+    None,
+    /// Cells inside the band show their uppercase form.
+    Upper,
+    /// Cells inside the band show their lowercase form.
+    Lower,
+}
+
+impl BandTransform {
+    /// Maps `ch`, keeping it unchanged when its case mapping is not a single char.
+    pub(crate) fn apply(self, ch: char) -> char {
+        match self {
+            BandTransform::None => ch,
+            BandTransform::Upper => single_char(ch.to_uppercase()).unwrap_or(ch),
+            BandTransform::Lower => single_char(ch.to_lowercase()).unwrap_or(ch),
+        }
+    }
+}
+
+/// The only char of `chars`, or `None` if it holds more than one.
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let first = chars.next();
+    chars.next().map_or(first, |_| None)
+}
+
+/// What the band does to the cells it passes over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EffectKind {
+    /// Text rests at the base style and the band lights it toward the highlight.
+    #[default]
+    Highlight,
+    /// The roles invert: text rests fully lit and a dark band sweeps across it, for "draining"
+    /// or "scanning" effects and light themes.
+    ///
+    /// Cells outside the band take the style the peak of a [`EffectKind::Highlight`] band
+    /// would have, including its `BOLD`; inside the band they blend back toward
+    /// [`ShimmerConfig::dark`], or the base color when unset. The 16-color ramp runs from
+    /// `White` down to `DarkGray`.
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{shimmer_spans_with_config, ColorMode, EffectKind, ShimmerConfig};
+    ///
+    /// let render = |effect| {
+    ///     let config = ShimmerConfig {
+    ///         color_mode: ColorMode::Basic16,
+    ///         band_half_width: 2,
+    ///         effect,
+    ///         ..ShimmerConfig::default()
+    ///     };
+    ///     shimmer_spans_with_config("Scanning...", Style::default(), 0.5, &config)
+    /// };
+    /// assert_eq!(
+    ///     render(EffectKind::Highlight),
+    ///     [
+    ///         Span::from("Scan").dark_gray().dim(),
+    ///         Span::from("n").gray(),
+    ///         Span::from("i").white().bold(),
+    ///         Span::from("n").gray(),
+    ///         Span::from("g...").dark_gray().dim(),
+    ///     ],
+    /// );
+    /// assert_eq!(
+    ///     render(EffectKind::ShadowSweep),
+    ///     [
+    ///         Span::from("Scan").white().bold(),
+    ///         Span::from("n").gray(),
+    ///         Span::from("i").dark_gray().dim(),
+    ///         Span::from("n").gray(),
+    ///         Span::from("g...").white().bold(),
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`ShimmerConfig::dark`]: crate::ShimmerConfig::dark
+    ShadowSweep,
+}
+
+/// Maps an RGB color to a palette index, for [`ShimmerConfig::indexed_resolver`].
+///
+/// [`ShimmerConfig::indexed_resolver`]: crate::ShimmerConfig::indexed_resolver
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedResolver(pub fn((u8, u8, u8)) -> u8);
+
+impl PartialEq for IndexedResolver {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::fn_addr_eq(self.0, other.0)
+    }
+}
+
+/// A darkening band that follows the highlight at a fixed distance.
+///
+/// Cells around `offset` cells behind the band center are blended toward black by up to
+/// `strength`, with the same falloff and width as the highlight.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{band_geometry, ColorMode, ShadowConfig, ShimmerConfig};
+///
+/// let plain = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let config = ShimmerConfig {
+///     shadow: Some(ShadowConfig { offset: 12, strength: 0.5 }),
+///     ..plain.clone()
+/// };
+/// let text = "Compiling the dependency graph for tests";
+/// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+/// let peak = band_geometry(text, 0.5, &config).peak_col as usize;
+///
+/// let shadowed = shimmer_styles(text, base, 0.5, &config);
+/// let unshadowed = shimmer_styles(text, base, 0.5, &plain);
+/// // The shadow center, `offset` cells behind the band, is darkened by `strength`.
+/// assert_eq!(unshadowed[peak - 12].fg, base.fg);
+/// assert_eq!(shadowed[peak - 12].fg, Some(Color::Rgb(50, 50, 50)));
+/// // Its neighbors are darkened less, and cells far from both bands keep the base.
+/// for index in peak - 14..peak - 12 {
+///     let Some(Color::Rgb(level, ..)) = shadowed[index].fg else { unreachable!() };
+///     assert!((50..100).contains(&level));
+/// }
+/// assert_eq!(shadowed[0].fg, base.fg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowConfig {
+    /// Cells between the band center and the shadow center; negative values lead the band.
+    pub offset: isize,
+    /// Darkening at the shadow center (0.0..=1.0).
+    pub strength: f32,
+}
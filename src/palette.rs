@@ -0,0 +1,160 @@
+use palette::{Clamp, FromColor, Hsl, Mix, Oklab, Srgb};
+use ratatui_core::style::Color;
+
+use crate::color::color_to_rgb;
+
+/// Converts a `palette` color into an RGB [`Color`] for fields such as
+/// [`ShimmerConfig::highlight`](crate::ShimmerConfig::highlight) or a base style.
+///
+/// Requires the `palette` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use palette::Srgb;
+/// use ratatui::style::Color;
+/// use tui_shimmer::{color_from_srgb, ShimmerConfig};
+///
+/// let config = ShimmerConfig {
+///     highlight: Some(color_from_srgb(Srgb::new(255u8, 204, 0))),
+///     ..ShimmerConfig::default()
+/// };
+/// assert_eq!(config.highlight, Some(Color::Rgb(255, 204, 0)));
+/// ```
+pub fn color_from_srgb(rgb: impl Into<Srgb<u8>>) -> Color {
+    let (r, g, b) = rgb.into().into_components();
+    // Custom RGB is intentional for shimmer.
+    #[allow(clippy::disallowed_methods)]
+    Color::Rgb(r, g, b)
+}
+
+/// Returns the RGB value the shimmer uses for `color`, or `None` for [`Color::Reset`].
+///
+/// Named and indexed colors map to the same palette the blend uses, so the result matches
+/// what [`ShimmerConfig::resolve`](crate::ShimmerConfig::resolve) reports.
+///
+/// Requires the `palette` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use palette::Srgb;
+/// use ratatui::style::Color;
+/// use tui_shimmer::{color_from_srgb, srgb_from_color};
+///
+/// assert_eq!(srgb_from_color(Color::Rgb(255, 204, 0)), Some(Srgb::new(255, 204, 0)));
+/// assert_eq!(srgb_from_color(Color::Indexed(196)), Some(Srgb::new(255, 0, 0)));
+/// assert_eq!(srgb_from_color(Color::Reset), None);
+///
+/// let teal = Srgb::new(0u8, 128, 128);
+/// assert_eq!(srgb_from_color(color_from_srgb(teal)), Some(teal));
+/// ```
+pub fn srgb_from_color(color: Color) -> Option<Srgb<u8>> {
+    color_to_rgb(color).map(Srgb::from_components)
+}
+
+/// Color space [`blend_srgb`] interpolates in.
+///
+/// Requires the `palette` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendSpace {
+    /// Gamma-encoded sRGB channels, the space the shimmer itself blends in.
+    #[default]
+    Srgb,
+    /// Linear light, which keeps the midpoint of two colors as bright as their average.
+    Linear,
+    /// Oklab, for perceptually even steps.
+    Oklab,
+    /// HSL, moving the hue along the shorter way around the color wheel.
+    Hsl,
+}
+
+/// Blends `from` toward `to` by `amount` (0.0..=1.0) in `space`, using `palette`'s conversions.
+///
+/// [`BlendSpace::Srgb`] matches the blend the span builders use to the nearest step, so the
+/// result of another space can be passed as a [`ShimmerConfig::highlight`] to preview the
+/// band's peak in it. A non-finite `amount` counts as 0.0.
+///
+/// Requires the `palette` feature.
+///
+/// [`ShimmerConfig::highlight`]: crate::ShimmerConfig::highlight
+///
+/// # Example
+///
+/// ```rust
+/// use palette::{FromColor, Hsl, Srgb};
+/// use ratatui::style::Style;
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{
+///     blend_srgb, color_from_srgb, intensity_at_with_config, srgb_from_color, BlendSpace,
+///     ColorMode, HighlightStyle, ShimmerConfig,
+/// };
+///
+/// let base = Srgb::new(40u8, 90, 160);
+/// let highlight = Srgb::new(250u8, 220, 120);
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     highlight: Some(color_from_srgb(highlight)),
+///     max_highlight: 1.0,
+///     ..ShimmerConfig::default()
+/// };
+/// let within_one = |a: Srgb<u8>, b: Srgb<u8>| {
+///     let (a, b) = (a.into_components(), b.into_components());
+///     a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1 && a.2.abs_diff(b.2) <= 1
+/// };
+///
+/// // The sRGB blend agrees with every cell the span builders color.
+/// let text = "Resolving dependencies";
+/// let style = Style::default().fg(color_from_srgb(base));
+/// for step in 0..=20 {
+///     let phase = step as f32 / 20.0;
+///     for (index, cell) in shimmer_styles(text, style, phase, &config).iter().enumerate() {
+///         let intensity = intensity_at_with_config(index, text.chars().count(), phase, &config);
+///         let expected = blend_srgb(base, highlight, intensity, BlendSpace::Srgb);
+///         assert!(within_one(srgb_from_color(cell.fg.unwrap()).unwrap(), expected));
+///     }
+/// }
+///
+/// // palette's HSL agrees with the built-in lightening of HighlightStyle::Lighten.
+/// let lighten = ShimmerConfig {
+///     highlight_style: HighlightStyle::Lighten(0.25),
+///     ..config.clone()
+/// };
+/// let peak = shimmer_styles("abc", style, 0.5, &lighten)[1].fg.unwrap();
+/// let mut hsl = Hsl::from_color(base.into_format::<f32>());
+/// hsl.lightness = (hsl.lightness + 0.25).min(1.0);
+/// let expected: Srgb<u8> = Srgb::from_color(hsl).into_format();
+/// assert!(within_one(srgb_from_color(peak).unwrap(), expected));
+///
+/// // Other spaces share the endpoints but differ in between.
+/// for space in [BlendSpace::Linear, BlendSpace::Oklab, BlendSpace::Hsl] {
+///     assert!(within_one(blend_srgb(base, highlight, 0.0, space), base));
+///     assert!(within_one(blend_srgb(base, highlight, 1.0, space), highlight));
+/// }
+/// let midpoint = |space| blend_srgb(base, highlight, 0.5, space);
+/// assert_ne!(midpoint(BlendSpace::Linear), midpoint(BlendSpace::Srgb));
+/// assert_ne!(midpoint(BlendSpace::Oklab), midpoint(BlendSpace::Srgb));
+/// ```
+pub fn blend_srgb(
+    from: impl Into<Srgb<u8>>,
+    to: impl Into<Srgb<u8>>,
+    amount: f32,
+    space: BlendSpace,
+) -> Srgb<u8> {
+    let amount = if amount.is_finite() {
+        amount.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let from: Srgb<f32> = from.into().into_format();
+    let to: Srgb<f32> = to.into().into_format();
+    let blended = match space {
+        BlendSpace::Srgb => from.mix(to, amount),
+        BlendSpace::Linear => Srgb::from_linear(from.into_linear().mix(to.into_linear(), amount)),
+        BlendSpace::Oklab => {
+            Srgb::from_color(Oklab::from_color(from).mix(Oklab::from_color(to), amount))
+        }
+        BlendSpace::Hsl => Srgb::from_color(Hsl::from_color(from).mix(Hsl::from_color(to), amount)),
+    };
+    blended.clamp().into_format()
+}
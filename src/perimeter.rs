@@ -0,0 +1,127 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+
+use crate::band::cosine_falloff;
+use crate::color::CellPainter;
+use crate::ShimmerConfig;
+
+/// Which perimeter cells [`apply_perimeter_band`] restyles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PerimeterCells {
+    /// Only cells holding a box-drawing glyph (`U+2500..=U+257F`), so titles stay untouched.
+    #[default]
+    BorderGlyphs,
+    /// Every cell on the perimeter.
+    All,
+}
+
+/// Restyles the perimeter of `area` with a short band travelling clockwise around it.
+///
+/// The perimeter is unrolled into a loop of `2 * (width + height) - 4` cells starting at the
+/// top-left corner, corners included. The band is centered at `phase * len` and covers
+/// `2 * band_half_width + 1` cells of the loop, wrapping from the left edge back to the top.
+/// Each touched cell keeps its own style as the base and is blended per `config`, so call
+/// this after the bordered widget has been rendered.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use ratatui::widgets::{Block, Widget};
+/// use tui_shimmer::{apply_perimeter_band, ColorMode, PerimeterCells, ShimmerConfig};
+///
+/// // A 6x4 border is a loop of 16 cells; a half-width of 2 lights 3 of them.
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     band_half_width: 2,
+///     ..ShimmerConfig::default()
+/// };
+/// let area = Rect::new(0, 0, 6, 4);
+/// let mut plain = Buffer::empty(area);
+/// Block::bordered()
+///     .border_style(Style::default().fg(Color::Rgb(100, 100, 100)))
+///     .render(area, &mut plain);
+/// let lit = |phase: f32| {
+///     let mut buf = plain.clone();
+///     apply_perimeter_band(&mut buf, area, phase, PerimeterCells::BorderGlyphs, &config);
+///     let mut lit: Vec<(u16, u16)> = area
+///         .positions()
+///         .filter(|position| buf[*position] != plain[*position])
+///         .map(|position| (position.x, position.y))
+///         .collect();
+///     lit.sort();
+///     lit
+/// };
+///
+/// // At 0.0 the band straddles the top-left corner, wrapping from the left edge to the top.
+/// assert_eq!(lit(0.0), [(0, 0), (0, 1), (1, 0)]);
+/// assert_eq!(lit(0.25), [(3, 0), (4, 0), (5, 0)]);
+/// assert_eq!(lit(0.5), [(4, 3), (5, 2), (5, 3)]);
+/// assert_eq!(lit(0.75), [(0, 3), (1, 3), (2, 3)]);
+/// assert_eq!(lit(15.0 / 16.0), [(0, 0), (0, 1), (0, 2)]);
+/// ```
+pub fn apply_perimeter_band(
+    buf: &mut Buffer,
+    area: Rect,
+    phase: f32,
+    cells: PerimeterCells,
+    config: &ShimmerConfig,
+) {
+    let area = area.intersection(buf.area);
+    let track = perimeter_track(area);
+    if track.is_empty() {
+        return;
+    }
+
+    let len = track.len();
+    let center = (phase.rem_euclid(1.0) * len as f32) as usize % len;
+    for (index, (x, y)) in track.into_iter().enumerate() {
+        let forward = (index + len - center) % len;
+        let dist = forward.min(len - forward);
+        if dist > config.band_half_width {
+            continue;
+        }
+        let Some(cell) = buf.cell_mut((x, y)) else {
+            continue;
+        };
+        if cells == PerimeterCells::BorderGlyphs && !is_border_glyph(cell.symbol()) {
+            continue;
+        }
+        let painter = CellPainter::new(cell.style(), config);
+        let style = painter.style(index, cosine_falloff(dist, config.band_half_width));
+        cell.set_style(style);
+    }
+}
+
+/// Perimeter cells of `area` in clockwise order from the top-left corner.
+fn perimeter_track(area: Rect) -> Vec<(u16, u16)> {
+    if area.is_empty() {
+        return Vec::new();
+    }
+    let (left, top) = (area.left(), area.top());
+    let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+    if area.width == 1 || area.height == 1 {
+        return area
+            .positions()
+            .map(|position| (position.x, position.y))
+            .collect();
+    }
+
+    let mut track =
+        Vec::with_capacity(2 * (usize::from(area.width) + usize::from(area.height)) - 4);
+    track.extend((left..=right).map(|x| (x, top)));
+    track.extend((top + 1..=bottom).map(|y| (right, y)));
+    track.extend((left..right).rev().map(|x| (x, bottom)));
+    track.extend((top + 1..bottom).rev().map(|y| (left, y)));
+    track
+}
+
+fn is_border_glyph(symbol: &str) -> bool {
+    let mut chars = symbol.chars();
+    matches!(
+        (chars.next(), chars.next()),
+        (Some('\u{2500}'..='\u{257F}'), None)
+    )
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+
+use crate::color::wash_style;
+use crate::{shimmer_spans_with_config, ShimmerConfig, ShimmerState};
+
+/// Frames an entry may go unused before [`ShimmerPool::tick`] drops it.
+const DEFAULT_IDLE_FRAMES: u32 = 60;
+
+/// Named shimmer indicators advanced together by a single tick.
+///
+/// Every entry shares the pool's phase, so indicators spread across an app stay in step
+/// without each owning a [`ShimmerState`]. Entries are created on first use by
+/// [`get_or_create`](ShimmerPool::get_or_create) and dropped once they have not been used for
+/// [`idle_frames`](ShimmerPool::idle_frames) ticks, so finished indicators do not linger.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::ShimmerPool;
+///
+/// let mut pool = ShimmerPool::new().idle_frames(2);
+///
+/// // Once per frame:
+/// pool.tick(Duration::from_millis(500));
+/// let line = pool.get_or_create("downloads.panel", "Downloading...", Style::default()).line();
+///
+/// // Every entry takes the same phase from the tick.
+/// let status = pool.get_or_create("status.bar", "Syncing", Style::default());
+/// assert_eq!(status.phase(), 0.25);
+/// assert_eq!(pool.len(), 2);
+///
+/// // Unused entries are dropped after the idle window.
+/// for _ in 0..3 {
+///     pool.tick(Duration::from_millis(16));
+/// }
+/// assert!(pool.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShimmerPool {
+    state: ShimmerState,
+    config: ShimmerConfig,
+    idle_frames: u32,
+    frame: u64,
+    entries: HashMap<String, ShimmerHandle>,
+}
+
+/// One indicator in a [`ShimmerPool`], as returned by [`ShimmerPool::get_or_create`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerHandle {
+    text: String,
+    base_style: Style,
+    config: ShimmerConfig,
+    phase_offset: f32,
+    phase: f32,
+    wash_phase: f32,
+    last_used: u64,
+}
+
+impl Default for ShimmerPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShimmerPool {
+    /// Creates an empty pool with the default 2 second sweep and a 60 frame idle window.
+    pub fn new() -> Self {
+        Self {
+            state: ShimmerState::new(),
+            config: ShimmerConfig::default(),
+            idle_frames: DEFAULT_IDLE_FRAMES,
+            frame: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Sets the duration of one full sweep in seconds. Defaults to 2 seconds.
+    #[must_use]
+    pub fn sweep_seconds(mut self, sweep_seconds: f32) -> Self {
+        self.state = self.state.sweep_seconds(sweep_seconds);
+        self
+    }
+
+    /// Sets the configuration given to entries created from now on. Defaults to
+    /// [`ShimmerConfig::default`].
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets how many ticks an entry may go without a
+    /// [`get_or_create`](ShimmerPool::get_or_create) before it is dropped. Defaults to 60.
+    #[must_use]
+    pub fn idle_frames(mut self, idle_frames: u32) -> Self {
+        self.idle_frames = idle_frames;
+        self
+    }
+
+    /// Advances every entry by `dt` and drops the ones idle for longer than the idle window.
+    ///
+    /// `dt` is scaled by the process-wide [`time_scale`](crate::time_scale) like
+    /// [`ShimmerState::tick`].
+    pub fn tick(&mut self, dt: Duration) {
+        self.state.tick(dt);
+        self.frame += 1;
+        let (frame, idle_frames) = (self.frame, u64::from(self.idle_frames));
+        self.entries
+            .retain(|_, entry| frame - entry.last_used <= idle_frames);
+    }
+
+    /// Current shared phase in `0.0..1.0`, before any per-entry offset.
+    pub fn phase(&self) -> f32 {
+        self.state.phase()
+    }
+
+    /// Returns the entry for `key`, creating it if needed, and marks it used this frame.
+    ///
+    /// `text` and `base_style` replace the entry's previous ones, so the call can be made
+    /// with the current label every frame.
+    pub fn get_or_create(&mut self, key: &str, text: &str, base_style: Style) -> &ShimmerHandle {
+        let phase = self.state.phase();
+        let frame = self.frame;
+        if !self.entries.contains_key(key) {
+            let entry = ShimmerHandle {
+                text: String::new(),
+                base_style,
+                config: self.config.clone(),
+                phase_offset: 0.0,
+                phase: 0.0,
+                wash_phase: 0.0,
+                last_used: frame,
+            };
+            self.entries.insert(key.to_owned(), entry);
+        }
+        let entry = self.entries.get_mut(key).expect("entry inserted above");
+        if entry.text != text {
+            entry.text = text.to_owned();
+        }
+        entry.base_style = base_style;
+        entry.last_used = frame;
+        entry.phase = (phase + entry.phase_offset).rem_euclid(1.0);
+        entry.wash_phase = self.state.wash_phase(&entry.config);
+        entry
+    }
+
+    /// Returns the entry for `key` for adjusting its overrides, without marking it used.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut ShimmerHandle> {
+        self.entries.get_mut(key)
+    }
+
+    /// Drops the entry for `key` right away, returning whether there was one.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.entries.remove(key).is_some()
+    }
+
+    /// Whether the pool holds an entry for `key`.
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Number of live entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the pool holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl ShimmerHandle {
+    /// Shifts this entry's band by `offset` of a cycle from the pool phase, so neighboring
+    /// indicators can sweep out of step. Applies from the next
+    /// [`get_or_create`](ShimmerPool::get_or_create).
+    pub fn set_phase_offset(&mut self, offset: f32) {
+        self.phase_offset = if offset.is_finite() { offset } else { 0.0 };
+    }
+
+    /// Replaces this entry's configuration, which starts as the pool's.
+    pub fn set_config(&mut self, config: ShimmerConfig) {
+        self.config = config;
+    }
+
+    /// This entry's configuration.
+    pub fn shimmer_config(&self) -> &ShimmerConfig {
+        &self.config
+    }
+
+    /// The text given to the last [`get_or_create`](ShimmerPool::get_or_create).
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Phase this entry rendered with at its last use: the pool phase plus its offset.
+    pub fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// Renders the entry's text at its phase.
+    pub fn spans(&self) -> Vec<Span<'static>> {
+        let base_style = wash_style(self.base_style, &self.config, self.wash_phase);
+        shimmer_spans_with_config(&self.text, base_style, self.phase, &self.config)
+    }
+
+    /// Renders the entry as a [`Line`].
+    pub fn line(&self) -> Line<'static> {
+        Line::from(self.spans())
+    }
+}
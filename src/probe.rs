@@ -0,0 +1,333 @@
+use std::io::{ErrorKind, Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{ColorMode, TerminalCapabilities};
+
+/// XTGETTCAP requests for the `RGB`, `Tc` and `colors` capabilities, then a DA1 request.
+///
+/// Every terminal answers DA1, so its reply marks the end of the answers. Write these bytes
+/// yourself and pass the replies to a [`CapabilityParser`] when driving the terminal through
+/// your own input loop.
+pub const CAPABILITY_QUERY: &[u8] =
+    b"\x1bP+q524742\x1b\\\x1bP+q5463\x1b\\\x1bP+q636f6c6f7273\x1b\\\x1b[c";
+
+/// Longest reply kept while waiting for the rest of it; longer sequences are dropped.
+const MAX_PENDING: usize = 4096;
+
+/// DA1 attribute for ANSI color support.
+const DA1_ANSI_COLOR: &str = "22";
+
+/// Pause between reads while no reply is pending.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Color support reported by the terminal itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProbedCaps {
+    /// The terminal reported the `RGB` or `Tc` capability, or more than 256 `colors`.
+    pub truecolor: bool,
+    /// Number of palette colors reported through the `colors` capability or DA1, saturating
+    /// at `u16::MAX`; 0 when neither reported it.
+    pub colors: u16,
+}
+
+impl ProbedCaps {
+    /// The color path matching these capabilities, for
+    /// [`set_color_support`](crate::set_color_support).
+    pub fn color_mode(&self) -> ColorMode {
+        if self.truecolor {
+            ColorMode::TrueColor
+        } else if self.colors >= 256 {
+            ColorMode::Indexed256
+        } else {
+            ColorMode::Basic16
+        }
+    }
+}
+
+impl From<ProbedCaps> for TerminalCapabilities {
+    fn from(caps: ProbedCaps) -> Self {
+        Self {
+            truecolor: caps.truecolor,
+            colors_256: caps.colors >= 256,
+        }
+    }
+}
+
+/// Incremental parser for the replies to [`CAPABILITY_QUERY`].
+///
+/// Feed it bytes as they are read; replies may be split across reads at any point. Bytes
+/// outside the expected replies, such as keys pressed during the probe, are skipped.
+///
+/// Requires the `probe` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_shimmer::{CapabilityParser, ColorMode};
+///
+/// // Replies in the shape of those from kitty, WezTerm and xterm (TERM=xterm-256color).
+/// let kitty: &[u8] = b"\x1bP1+r524742=382f382f38\x1b\\\x1bP1+r5463\x1b\\\x1bP1+r636f6c6f7273=323536\x1b\\\x1b[?62;c";
+/// let wezterm: &[u8] = b"\x1bP0+r524742\x1b\\\x1bP1+r5463\x1b\\\x1bP1+r636f6c6f7273=323536\x1b\\\x1b[?65;4;6;18;22c";
+/// let xterm: &[u8] = b"\x1bP0+r524742\x1b\\\x1bP0+r5463\x1b\\\x1bP1+r636f6c6f7273=323536\x1b\\\x1b[?64;1;2;6;9;15;16;17;18;21;22;28c";
+///
+/// for (reply, mode) in [
+///     (kitty, ColorMode::TrueColor),
+///     (wezterm, ColorMode::TrueColor),
+///     (xterm, ColorMode::Indexed256),
+/// ] {
+///     // A key pressed during the probe, then the reply split into short reads.
+///     let mut parser = CapabilityParser::new();
+///     assert_eq!(parser.feed(b"j"), None);
+///     let mut caps = None;
+///     for chunk in reply.chunks(5) {
+///         assert!(caps.is_none());
+///         caps = parser.feed(chunk);
+///     }
+///     let caps = caps.unwrap();
+///     assert_eq!(caps.color_mode(), mode);
+///     assert_eq!(caps.colors, 256);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CapabilityParser {
+    pending: Vec<u8>,
+    caps: ProbedCaps,
+}
+
+impl CapabilityParser {
+    /// Creates a parser that has seen no replies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes `bytes`, returning the capabilities once the DA1 reply has arrived.
+    pub fn feed(&mut self, bytes: &[u8]) -> Option<ProbedCaps> {
+        self.pending.extend_from_slice(bytes);
+        let mut start = 0;
+        let result = loop {
+            let Some(escape) = find(&self.pending[start..], b"\x1b").map(|at| start + at) else {
+                start = self.pending.len();
+                break None;
+            };
+            match self.parse_sequence(escape) {
+                Sequence::Incomplete => {
+                    start = escape;
+                    break None;
+                }
+                Sequence::Skipped(end) => start = end,
+                Sequence::Done(end) => {
+                    start = end;
+                    break Some(self.caps);
+                }
+            }
+        };
+        self.pending.drain(..start);
+        if self.pending.len() > MAX_PENDING {
+            self.pending.clear();
+        }
+        result
+    }
+
+    /// Parses the sequence starting with the escape at `at`.
+    fn parse_sequence(&mut self, at: usize) -> Sequence {
+        let rest = &self.pending[at..];
+        match rest.get(1) {
+            None => Sequence::Incomplete,
+            Some(b'P') => {
+                let Some(len) = find(&rest[2..], b"\x1b\\") else {
+                    return Sequence::Incomplete;
+                };
+                let body = rest[2..2 + len].to_vec();
+                self.parse_tcap_reply(&body);
+                Sequence::Skipped(at + 2 + len + 2)
+            }
+            Some(b'[') => {
+                let Some(len) = rest[2..]
+                    .iter()
+                    .position(|byte| (0x40..=0x7e).contains(byte))
+                else {
+                    return Sequence::Incomplete;
+                };
+                let params = &rest[2..2 + len];
+                let end = at + 2 + len + 1;
+                match (rest[2 + len], params.split_first()) {
+                    (b'c', Some((b'?', attributes))) => {
+                        let ansi_color = String::from_utf8_lossy(attributes)
+                            .split(';')
+                            .any(|attribute| attribute == DA1_ANSI_COLOR);
+                        if ansi_color && self.caps.colors == 0 {
+                            self.caps.colors = 8;
+                        }
+                        Sequence::Done(end)
+                    }
+                    _ => Sequence::Skipped(end),
+                }
+            }
+            Some(_) => Sequence::Skipped(at + 1),
+        }
+    }
+
+    /// Records the capabilities in an XTGETTCAP reply body (between `ESC P` and `ESC \`).
+    fn parse_tcap_reply(&mut self, body: &[u8]) {
+        let Some(pairs) = body.strip_prefix(b"1+r") else {
+            return;
+        };
+        for pair in pairs.split(|byte| *byte == b';') {
+            let mut parts = pair.splitn(2, |byte| *byte == b'=');
+            let name = parts.next().and_then(decode_hex);
+            let value = parts.next().and_then(decode_hex);
+            match (name.as_deref(), value) {
+                (Some("RGB" | "Tc"), _) => self.caps.truecolor = true,
+                (Some("colors"), Some(value)) => {
+                    if let Ok(colors) = value.parse::<u32>() {
+                        self.caps.colors = u16::try_from(colors).unwrap_or(u16::MAX);
+                        self.caps.truecolor |= colors > 256;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Outcome of parsing one escape sequence.
+enum Sequence {
+    /// More bytes are needed.
+    Incomplete,
+    /// A sequence that does not finish the probe, ending before the given offset.
+    Skipped(usize),
+    /// The DA1 reply, ending before the given offset.
+    Done(usize),
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Decodes hex-encoded ASCII, as used for XTGETTCAP names and values.
+fn decode_hex(hex: &[u8]) -> Option<String> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    hex.chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(pair, 16).ok().map(char::from)
+        })
+        .collect()
+}
+
+/// Asks the terminal which colors it supports, waiting up to `timeout` for the answer.
+///
+/// Writes [`CAPABILITY_QUERY`] to the controlling terminal and parses the replies with a
+/// [`CapabilityParser`]. Returns `None` when there is no terminal or it does not answer in
+/// time. Pass the result to [`set_color_support`](crate::set_color_support) via
+/// [`ProbedCaps::color_mode`].
+///
+/// The crate never probes on its own; call this explicitly, once, before starting your input
+/// loop. The terminal must be in raw mode so the replies are not echoed or line-buffered.
+/// Keys pressed during the probe are consumed. The terminal is read without blocking until
+/// the replies arrive or `timeout` passes, and is closed before this returns, so nothing is
+/// left reading input afterwards.
+///
+/// Requires the `probe` feature. Only Linux, Android, macOS, the BSDs, Solaris and illumos
+/// terminals are supported; elsewhere this returns `None`.
+pub fn probe_terminal_capabilities(timeout: Duration) -> Option<ProbedCaps> {
+    let deadline = Instant::now() + timeout;
+    let mut tty = open_tty()?;
+    tty.write_all(CAPABILITY_QUERY).ok()?;
+    tty.flush().ok()?;
+
+    let mut parser = CapabilityParser::new();
+    let mut buf = [0; 256];
+    while Instant::now() < deadline {
+        match tty.read(&mut buf) {
+            Ok(0) => return None,
+            Ok(read) => {
+                if let Some(caps) = parser.feed(&buf[..read]) {
+                    return Some(caps);
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+/// `O_NONBLOCK` for the targets [`open_tty`] supports, so reads return instead of waiting
+/// for input.
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ))
+))]
+const O_NONBLOCK: i32 = 0o4000;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    any(target_arch = "mips", target_arch = "mips64")
+))]
+const O_NONBLOCK: i32 = 0o200;
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    any(target_arch = "sparc", target_arch = "sparc64")
+))]
+const O_NONBLOCK: i32 = 0x4000;
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+const O_NONBLOCK: i32 = 0x4;
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+const O_NONBLOCK: i32 = 0x80;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos"
+))]
+fn open_tty() -> Option<std::fs::File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(O_NONBLOCK)
+        .open("/dev/tty")
+        .ok()
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly",
+    target_os = "solaris",
+    target_os = "illumos"
+)))]
+fn open_tty() -> Option<std::fs::File> {
+    None
+}
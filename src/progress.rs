@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::{shimmer_spans_with_config, ShimmerConfig, SHIMMER_SWEEP_SECONDS};
+
+/// Share of the 2 second sweep left at full progress, capping the speed-up at 8x.
+const MIN_SWEEP_SHARE: f32 = 0.125;
+
+/// Renders `text` with a band that sweeps faster as `progress` (0.0..=1.0) nears completion.
+///
+/// The sweep takes the default 2 seconds scaled by `1.0 - progress`, down to a quarter
+/// second, and the phase is `elapsed` over that duration; see [`progress_speed_phase`].
+/// Nothing is remembered between calls, so a jump in `progress` can move the band. For a
+/// speed change that carries the phase over, use
+/// [`ShimmerState::set_eta`](crate::ShimmerState::set_eta).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_spans_progress_speed, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let elapsed = Duration::from_millis(1500);
+/// let spans = shimmer_spans_progress_speed("Downloading...", Style::default(), 0.4, elapsed, &config);
+/// ```
+pub fn shimmer_spans_progress_speed(
+    text: &str,
+    base_style: Style,
+    progress: f32,
+    elapsed: Duration,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    let phase = progress_speed_phase(progress, elapsed);
+    shimmer_spans_with_config(text, base_style, phase, config)
+}
+
+/// Phase (0.0..1.0) rendered by [`shimmer_spans_progress_speed`] at `progress` after `elapsed`.
+///
+/// `progress` is clamped to 0.0..=1.0, with non-finite values counting as 0.0.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use tui_shimmer::{band_geometry, progress_speed_phase, ShimmerConfig};
+///
+/// let config = ShimmerConfig::default();
+/// let moved = |progress| {
+///     let peak = |ms| {
+///         band_geometry(&"=".repeat(20), progress_speed_phase(progress, Duration::from_millis(ms)), &config)
+///             .peak_col
+///     };
+///     peak(250) - peak(0)
+/// };
+/// // Over the same 250 ms the band moves 5 cells at the start and 10 halfway through.
+/// assert_eq!(moved(0.0), 5.0);
+/// assert_eq!(moved(0.5), 10.0);
+/// assert!(moved(0.8) > moved(0.5));
+/// ```
+pub fn progress_speed_phase(progress: f32, elapsed: Duration) -> f32 {
+    let progress = if progress.is_finite() {
+        progress.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let sweep_seconds = SHIMMER_SWEEP_SECONDS * (1.0 - progress).max(MIN_SWEEP_SHARE);
+    (elapsed.as_secs_f64() / f64::from(sweep_seconds)).rem_euclid(1.0) as f32
+}
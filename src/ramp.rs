@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use ratatui_core::style::Modifier;
+
+use crate::ShimmerError;
+
+const TRUE_COLOR_STOPS: &[(f32, Modifier)] = &[
+    (0.0, Modifier::empty()),
+    (f32::MIN_POSITIVE, Modifier::BOLD),
+];
+const FALLBACK_STOPS: &[(f32, Modifier)] = &[
+    (0.0, Modifier::DIM),
+    (0.2, Modifier::empty()),
+    (0.6, Modifier::BOLD),
+];
+
+/// Modifiers applied to a cell according to its band intensity.
+///
+/// Each stop `(threshold, modifier)` applies from its threshold up to the next stop; cells
+/// below the first threshold get no modifier. For example DIM below 0.2, nothing up to 0.6,
+/// ITALIC up to 0.85 and BOLD above:
+///
+/// ```rust
+/// use ratatui::style::Modifier;
+/// use tui_shimmer::ModifierRamp;
+///
+/// let ramp = ModifierRamp::new(vec![
+///     (0.0, Modifier::DIM),
+///     (0.2, Modifier::empty()),
+///     (0.6, Modifier::ITALIC),
+///     (0.85, Modifier::BOLD),
+/// ])?;
+/// assert_eq!(ramp.modifier_at(0.7), Modifier::ITALIC);
+///
+/// // Each stop applies from exactly its threshold.
+/// assert_eq!(ramp.modifier_at(-0.1), Modifier::empty());
+/// assert_eq!(ramp.modifier_at(0.0), Modifier::DIM);
+/// assert_eq!(ramp.modifier_at(0.199), Modifier::DIM);
+/// assert_eq!(ramp.modifier_at(0.2), Modifier::empty());
+/// assert_eq!(ramp.modifier_at(0.6), Modifier::ITALIC);
+/// assert_eq!(ramp.modifier_at(0.849), Modifier::ITALIC);
+/// assert_eq!(ramp.modifier_at(0.85), Modifier::BOLD);
+/// assert_eq!(ramp.modifier_at(1.0), Modifier::BOLD);
+/// # Ok::<(), tui_shimmer::ShimmerError>(())
+/// ```
+///
+/// The defaults reproduce the fixed rules the span builders used before ramps were
+/// configurable: BOLD on every lit cell on the RGB path, and DIM, nothing or BOLD alongside
+/// the three gray levels on the fallback path.
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{intensity_at, ColorMode, ModifierRamp, ShimmerConfig};
+///
+/// let text = "Loading the package index";
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// let rgb = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let fallback = ShimmerConfig { color_mode: ColorMode::Basic16, ..ShimmerConfig::default() };
+/// for step in 0..=50 {
+///     let phase = step as f32 / 50.0;
+///     let intensity = |index| intensity_at(index, 25, phase);
+///     for (index, style) in shimmer_styles(text, base, phase, &rgb).iter().enumerate() {
+///         let bold = if intensity(index) > 0.0 { Modifier::BOLD } else { Modifier::empty() };
+///         assert_eq!(style.add_modifier, bold);
+///     }
+///     for (index, style) in shimmer_styles(text, base, phase, &fallback).iter().enumerate() {
+///         let expected = match intensity(index) {
+///             level if level < 0.2 => base.fg(Color::DarkGray).add_modifier(Modifier::DIM),
+///             level if level < 0.6 => base.fg(Color::Gray),
+///             _ => base.fg(Color::White).add_modifier(Modifier::BOLD),
+///         };
+///         assert_eq!(*style, expected);
+///     }
+///
+///     // Setting the defaults explicitly changes nothing.
+///     for (config, ramp) in [(&rgb, ModifierRamp::TRUE_COLOR_DEFAULT), (&fallback, ModifierRamp::FALLBACK_DEFAULT)] {
+///         let explicit = ShimmerConfig { modifier_ramp: Some(ramp), ..config.clone() };
+///         assert_eq!(shimmer_styles(text, base, phase, &explicit), shimmer_styles(text, base, phase, config));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifierRamp {
+    stops: Cow<'static, [(f32, Modifier)]>,
+}
+
+impl ModifierRamp {
+    /// BOLD on every cell inside the band; the default for the RGB and indexed paths.
+    pub const TRUE_COLOR_DEFAULT: ModifierRamp = ModifierRamp {
+        stops: Cow::Borrowed(TRUE_COLOR_STOPS),
+    };
+
+    /// DIM below 0.2, nothing below 0.6, BOLD above; the default for the modifier paths.
+    pub const FALLBACK_DEFAULT: ModifierRamp = ModifierRamp {
+        stops: Cow::Borrowed(FALLBACK_STOPS),
+    };
+
+    /// No modifiers at any intensity, for bands that should change color only.
+    pub const NONE: ModifierRamp = ModifierRamp {
+        stops: Cow::Borrowed(&[]),
+    };
+
+    /// Creates a ramp, rejecting stops whose thresholds are not finite and strictly ascending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Modifier;
+    /// use tui_shimmer::{ModifierRamp, ShimmerError};
+    ///
+    /// let ramp = |thresholds: &[f32]| {
+    ///     ModifierRamp::new(thresholds.iter().map(|&threshold| (threshold, Modifier::BOLD)).collect())
+    /// };
+    /// assert!(ramp(&[0.0, 0.5, 0.9]).is_ok());
+    /// assert_eq!(ramp(&[0.5, 0.2]), Err(ShimmerError::UnsortedStops { index: 1 }));
+    /// assert_eq!(ramp(&[0.2, 0.2]), Err(ShimmerError::UnsortedStops { index: 1 }));
+    /// assert_eq!(ramp(&[f32::NAN]), Err(ShimmerError::UnsortedStops { index: 0 }));
+    /// assert_eq!(ramp(&[0.0, f32::INFINITY]), Err(ShimmerError::UnsortedStops { index: 1 }));
+    /// ```
+    pub fn new(stops: Vec<(f32, Modifier)>) -> Result<Self, ShimmerError> {
+        let mut previous = f32::NEG_INFINITY;
+        for (index, (threshold, _)) in stops.iter().enumerate() {
+            if !threshold.is_finite() || *threshold <= previous {
+                return Err(ShimmerError::UnsortedStops { index });
+            }
+            previous = *threshold;
+        }
+        Ok(Self {
+            stops: Cow::Owned(stops),
+        })
+    }
+
+    /// The validated stops in ascending threshold order.
+    pub fn stops(&self) -> &[(f32, Modifier)] {
+        &self.stops
+    }
+
+    /// Modifier for a cell at `intensity`.
+    pub fn modifier_at(&self, intensity: f32) -> Modifier {
+        modifier_at(&self.stops, intensity)
+    }
+}
+
+pub(crate) fn modifier_at(stops: &[(f32, Modifier)], intensity: f32) -> Modifier {
+    stops
+        .iter()
+        .take_while(|(threshold, _)| intensity >= *threshold)
+        .last()
+        .map_or(Modifier::empty(), |(_, modifier)| *modifier)
+}
+
+pub(crate) fn default_stops(true_color: bool) -> &'static [(f32, Modifier)] {
+    if true_color {
+        TRUE_COLOR_STOPS
+    } else {
+        FALLBACK_STOPS
+    }
+}
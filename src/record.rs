@@ -0,0 +1,278 @@
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::Span;
+
+use crate::{build_span, shimmer_spans_with_config, ShimmerConfig};
+
+/// First line of every recording; bump the version when the record layout changes.
+const HEADER: &str = "tui-shimmer-recording 1";
+
+/// One rendered frame read back by [`ShimmerReplayer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedFrame {
+    /// Time of the frame relative to the start of the recording.
+    pub timestamp: Duration,
+    /// FNV-1a hash of the rendered text, for spotting which message was on screen.
+    pub text_hash: u64,
+    /// Phase the frame was rendered at.
+    pub phase: f32,
+    /// The spans exactly as they were rendered.
+    pub spans: Vec<Span<'static>>,
+}
+
+/// Writes rendered shimmer frames to a recording that [`ShimmerReplayer`] can play back.
+///
+/// The format is line-based text: a version header, then one tab-separated record per frame
+/// holding the timestamp, text hash, phase and every span's colors, modifiers and content.
+///
+/// Requires the `record` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{ShimmerConfig, ShimmerRecorder, ShimmerReplayer};
+///
+/// let mut recorder = ShimmerRecorder::new(Vec::new())?;
+/// let config = ShimmerConfig::default();
+/// let texts = ["Loading...", "Loading...", "Tabs\tand\nnewlines", "Loading..."];
+/// let mut rendered = Vec::new();
+/// for (frame, text) in texts.iter().enumerate() {
+///     let timestamp = Duration::from_millis(40 * frame as u64);
+///     let phase = frame as f32 / 7.0;
+///     rendered.push(recorder.spans(timestamp, text, Style::default(), phase, &config)?);
+/// }
+///
+/// let frames: Vec<_> = ShimmerReplayer::new(recorder.into_inner().as_slice())?.collect();
+/// assert_eq!(frames.len(), 4);
+/// for (frame, (recorded, spans)) in frames.iter().zip(&rendered).enumerate() {
+///     assert_eq!(recorded.timestamp, Duration::from_millis(40 * frame as u64));
+///     assert_eq!(recorded.phase, frame as f32 / 7.0);
+///     assert_eq!(&recorded.spans, spans);
+/// }
+/// // Frames of the same text share a hash.
+/// assert_eq!(frames[0].text_hash, frames[1].text_hash);
+/// assert_eq!(frames[0].text_hash, frames[3].text_hash);
+/// assert_ne!(frames[0].text_hash, frames[2].text_hash);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ShimmerRecorder<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> ShimmerRecorder<W> {
+    /// Starts a recording by writing the format header to `writer`.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writeln!(writer, "{HEADER}")?;
+        Ok(Self { writer })
+    }
+
+    /// Renders `text` like [`shimmer_spans_with_config`] and records the frame at `timestamp`.
+    pub fn spans(
+        &mut self,
+        timestamp: Duration,
+        text: &str,
+        base_style: Style,
+        phase: f32,
+        config: &ShimmerConfig,
+    ) -> io::Result<Vec<Span<'static>>> {
+        let spans = shimmer_spans_with_config(text, base_style, phase, config);
+        self.record(timestamp, text, phase, &spans)?;
+        Ok(spans)
+    }
+
+    /// Records spans rendered elsewhere, such as by [`ShimmerState::spans`](crate::ShimmerState::spans).
+    pub fn record(
+        &mut self,
+        timestamp: Duration,
+        text: &str,
+        phase: f32,
+        spans: &[Span<'_>],
+    ) -> io::Result<()> {
+        write!(
+            self.writer,
+            "{}\t{:016x}\t{:08x}\t{}",
+            timestamp.as_nanos(),
+            text_hash(text),
+            phase.to_bits(),
+            spans.len()
+        )?;
+        for span in spans {
+            let style = span.style;
+            write!(
+                self.writer,
+                "\t{}\t{}\t{}\t{}\t",
+                color_field(style.fg),
+                color_field(style.bg),
+                style.add_modifier.bits(),
+                style.sub_modifier.bits()
+            )?;
+            write_escaped(&mut self.writer, &span.content)?;
+        }
+        writeln!(self.writer)
+    }
+
+    /// Finishes the recording and returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Plays back the frames of a recording made by [`ShimmerRecorder`], in order.
+///
+/// Frames are read up front, so iterating is independent of the clock and of I/O.
+///
+/// Requires the `record` feature.
+#[derive(Debug, Clone)]
+pub struct ShimmerReplayer {
+    frames: std::vec::IntoIter<RecordedFrame>,
+}
+
+impl ShimmerReplayer {
+    /// Reads a recording from `reader`.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the header is missing or names another
+    /// format version, or if a record is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::ErrorKind;
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::Style;
+    /// use tui_shimmer::{ShimmerConfig, ShimmerRecorder, ShimmerReplayer};
+    ///
+    /// let mut recorder = ShimmerRecorder::new(Vec::new())?;
+    /// recorder.spans(Duration::ZERO, "Loading...", Style::default(), 0.5, &ShimmerConfig::default())?;
+    /// let recording = String::from_utf8(recorder.into_inner()).unwrap();
+    /// let (_, frames) = recording.split_once('\n').unwrap();
+    ///
+    /// let older = format!("tui-shimmer-recording 0\n{frames}");
+    /// for bad in [older.as_str(), frames, ""] {
+    ///     let error = ShimmerReplayer::new(bad.as_bytes()).unwrap_err();
+    ///     assert_eq!(error.kind(), ErrorKind::InvalidData);
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new(reader: impl BufRead) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        match lines.next().transpose()? {
+            Some(header) if header == HEADER => {}
+            _ => {
+                return Err(invalid_data(
+                    "not a tui-shimmer recording of a supported version",
+                ))
+            }
+        }
+        let frames = lines
+            .map(|line| parse_frame(&line?))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self {
+            frames: frames.into_iter(),
+        })
+    }
+}
+
+impl Iterator for ShimmerReplayer {
+    type Item = RecordedFrame;
+
+    fn next(&mut self) -> Option<RecordedFrame> {
+        self.frames.next()
+    }
+}
+
+fn parse_frame(line: &str) -> io::Result<RecordedFrame> {
+    let malformed = || invalid_data("malformed recording frame");
+    let mut fields = line.split('\t');
+    let mut field = || fields.next().ok_or_else(malformed);
+
+    let timestamp = field()?.parse::<u64>().map_err(|_| malformed())?;
+    let text_hash = u64::from_str_radix(field()?, 16).map_err(|_| malformed())?;
+    let phase = u32::from_str_radix(field()?, 16).map_err(|_| malformed())?;
+    let count = field()?.parse::<usize>().map_err(|_| malformed())?;
+
+    let mut spans = Vec::with_capacity(count);
+    for _ in 0..count {
+        let fg = parse_color_field(field()?).ok_or_else(malformed)?;
+        let bg = parse_color_field(field()?).ok_or_else(malformed)?;
+        let add = field()?.parse::<u16>().map_err(|_| malformed())?;
+        let sub = field()?.parse::<u16>().map_err(|_| malformed())?;
+        let content = unescape(field()?).ok_or_else(malformed)?;
+        let mut style = Style::default()
+            .add_modifier(Modifier::from_bits_truncate(add))
+            .remove_modifier(Modifier::from_bits_truncate(sub));
+        style.fg = fg;
+        style.bg = bg;
+        spans.push(build_span(content, style));
+    }
+    if fields.next().is_some() {
+        return Err(malformed());
+    }
+
+    Ok(RecordedFrame {
+        timestamp: Duration::from_nanos(timestamp),
+        text_hash,
+        phase: f32::from_bits(phase),
+        spans,
+    })
+}
+
+fn color_field(color: Option<Color>) -> String {
+    color.map_or_else(|| "-".to_owned(), |color| color.to_string())
+}
+
+/// `Some(None)` for an unset color, `None` when the field does not parse.
+fn parse_color_field(field: &str) -> Option<Option<Color>> {
+    match field {
+        "-" => Some(None),
+        color => color.parse().ok().map(Some),
+    }
+}
+
+fn write_escaped(writer: &mut impl Write, content: &str) -> io::Result<()> {
+    for ch in content.chars() {
+        match ch {
+            '\\' => writer.write_all(b"\\\\")?,
+            '\t' => writer.write_all(b"\\t")?,
+            '\n' => writer.write_all(b"\\n")?,
+            '\r' => writer.write_all(b"\\r")?,
+            ch => write!(writer, "{ch}")?,
+        }
+    }
+    Ok(())
+}
+
+fn unescape(field: &str) -> Option<String> {
+    let mut content = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(ch) = chars.next() {
+        content.push(match ch {
+            '\\' => match chars.next()? {
+                '\\' => '\\',
+                't' => '\t',
+                'n' => '\n',
+                'r' => '\r',
+                _ => return None,
+            },
+            ch => ch,
+        });
+    }
+    Some(content)
+}
+
+/// 64-bit FNV-1a, stable across platforms and releases unlike `DefaultHasher`.
+fn text_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Line;
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{coalesce_spans, ColorMode, ShimmerConfig, ShimmerError};
+
+/// Renders `text` with several independently shimmering regions in one line.
+///
+/// Each region is a char range paired with whether it animates. Animated regions run their
+/// own band sized to the region; with a non-zero `stagger` region `n` is offset by
+/// `n * stagger` of a cycle so neighbouring fields do not pulse in lockstep. Cells outside
+/// animated regions keep `base_style`.
+///
+/// Regions must lie within the text, in ascending order and without overlapping; otherwise
+/// [`ShimmerError::InvalidRegion`] reports the first offending region.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::{
+///     shimmer_regions, shimmer_spans_with_config, ColorMode, ShimmerConfig, ShimmerError,
+/// };
+///
+/// fn to_cells(spans: &[Span]) -> Vec<(char, Style)> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|ch| (ch, span.style))).collect()
+/// }
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(120, 120, 120));
+/// let text = "CPU: 12%  MEM: …  NET: 3 MB/s";
+/// let line = shimmer_regions(
+///     text,
+///     base,
+///     0.5,
+///     &[(0..8, false), (10..16, true), (18..29, false)],
+///     0.0,
+///     &config,
+/// )?;
+///
+/// // Only the middle field shimmers, with a band sized to that field alone.
+/// let cells = to_cells(&line.spans);
+/// let field = to_cells(&shimmer_spans_with_config("MEM: …", base, 0.5, &config));
+/// assert!(cells[..10].iter().chain(&cells[16..]).all(|(_, style)| *style == base));
+/// assert_eq!(cells[10..16], field);
+/// assert!(field.iter().any(|(_, style)| *style != base));
+///
+/// // Overlapping, reversed and out-of-bounds regions are rejected.
+/// let invalid = |regions: &[(std::ops::Range<usize>, bool)]| {
+///     shimmer_regions(text, base, 0.5, regions, 0.0, &config).unwrap_err()
+/// };
+/// assert_eq!(invalid(&[(0..8, false), (6..12, true)]), ShimmerError::InvalidRegion { index: 1 });
+/// assert_eq!(invalid(&[(8..4, true)]), ShimmerError::InvalidRegion { index: 0 });
+/// assert_eq!(invalid(&[(0..8, false), (20..40, true)]), ShimmerError::InvalidRegion { index: 1 });
+/// # Ok::<(), ShimmerError>(())
+/// ```
+pub fn shimmer_regions(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    regions: &[(Range<usize>, bool)],
+    stagger: f32,
+    config: &ShimmerConfig,
+) -> Result<Line<'static>, ShimmerError> {
+    let char_count = text.chars().count();
+    let mut previous_end = 0;
+    for (index, (range, _)) in regions.iter().enumerate() {
+        if range.start < previous_end || range.start > range.end || range.end > char_count {
+            return Err(ShimmerError::InvalidRegion { index });
+        }
+        previous_end = range.end;
+    }
+
+    let painter = CellPainter::new(base_style, config);
+    if painter.mode() == ColorMode::Plain {
+        return Ok(Line::styled(text.to_owned(), base_style));
+    }
+
+    let bands: Vec<Option<Band>> = regions
+        .iter()
+        .enumerate()
+        .map(|(index, (range, animate))| {
+            animate.then(|| Band::new(range.len(), phase + index as f32 * stagger, config))
+        })
+        .collect();
+
+    let mut region = 0;
+    let cells = text.chars().enumerate().map(|(index, ch)| {
+        while regions
+            .get(region)
+            .is_some_and(|(range, _)| range.end <= index)
+        {
+            region += 1;
+        }
+        let style = match (regions.get(region), bands.get(region)) {
+            (Some((range, _)), Some(Some(band))) if range.contains(&index) => {
+                painter.style(index, band.intensity(index - range.start))
+            }
+            _ => base_style,
+        };
+        (ch, style)
+    });
+    Ok(Line::from(coalesce_spans(cells, char_count)))
+}
@@ -0,0 +1,95 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+#[cfg(feature = "std")]
+use crate::clock::{shimmer_phase_at, shimmer_phase_from_elapsed};
+use crate::color_mode::detected_color_mode;
+use crate::{ColorMode, Frame, ShimmerConfig};
+
+/// The phase and detected color support for one render pass, shared by every label in it.
+///
+/// Capture it once per frame with [`ShimmerFrame::now`] and render each label with
+/// [`spans`](ShimmerFrame::spans). The clock and color detection are read once instead of per
+/// label, and every label sweeps at exactly the same phase.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::{shimmer_spans_with_config, ShimmerConfig, ShimmerFrame};
+///
+/// // `ShimmerFrame::now()` reads the global clock; an explicit phase works without `std`.
+/// let frame = ShimmerFrame::at_phase(0.3);
+/// let config = ShimmerConfig::default();
+/// let first = frame.spans("Loading...", Style::default(), &config);
+/// let second = frame.spans("Indexing...", Style::default(), &config);
+///
+/// assert_eq!(first, shimmer_spans_with_config("Loading...", Style::default(), frame.phase(), &config));
+/// assert_eq!(second, shimmer_spans_with_config("Indexing...", Style::default(), frame.phase(), &config));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShimmerFrame {
+    phase: f32,
+    color_mode: ColorMode,
+}
+
+impl ShimmerFrame {
+    /// Captures the global clock's current phase, as used by
+    /// [`shimmer_spans_with_style`](crate::shimmer_spans_with_style).
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::at_phase(shimmer_phase_from_elapsed())
+    }
+
+    /// Captures the global clock's phase at `at`; see [`shimmer_phase_at`].
+    ///
+    /// Requires the `std` feature (enabled by default).
+    #[cfg(feature = "std")]
+    pub fn at(at: std::time::Instant) -> Self {
+        Self::at_phase(shimmer_phase_at(at))
+    }
+
+    /// Captures an explicit phase (0.0..1.0), such as one from a
+    /// [`ShimmerState`](crate::ShimmerState).
+    pub fn at_phase(phase: f32) -> Self {
+        Self {
+            phase,
+            color_mode: detected_color_mode(),
+        }
+    }
+
+    /// The phase every label in this pass renders at.
+    pub fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// The color path [`ColorMode::Auto`] resolves to in this pass.
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Renders `text` like [`shimmer_spans_with_config`](crate::shimmer_spans_with_config) at
+    /// this pass's phase, resolving [`ColorMode::Auto`] to the captured color mode unless
+    /// `config` carries its own [`capabilities`](ShimmerConfig::capabilities).
+    pub fn spans(
+        &self,
+        text: &str,
+        base_style: Style,
+        config: &ShimmerConfig,
+    ) -> Vec<Span<'static>> {
+        let char_count = text.chars().count();
+        if char_count == 0 {
+            return Vec::new();
+        }
+        let mode = config.gate_mode(match (config.color_mode, config.capabilities) {
+            (ColorMode::Auto, Some(capabilities)) => capabilities.color_mode(),
+            (ColorMode::Auto, None) => self.color_mode,
+            (mode, _) => mode,
+        });
+        Frame::with_mode(text, char_count, base_style, self.phase, config, mode)
+            .spans(text, char_count)
+            .0
+    }
+}
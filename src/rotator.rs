@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+
+use crate::color::CellPainter;
+use crate::{
+    coalesce_spans, intensity_at_with_config, shimmer_spans_with_config, time_scale, ShimmerConfig,
+    SHIMMER_SWEEP_SECONDS,
+};
+
+const DEFAULT_DWELL: Duration = Duration::from_secs(3);
+const DEFAULT_CROSSFADE: Duration = Duration::from_millis(300);
+
+/// Cycles through loading phrases, shimmering the current one and wiping between them.
+///
+/// Each phrase is shown for the [`dwell`](PhraseRotator::dwell) duration. During the first
+/// [`crossfade`](PhraseRotator::crossfade) of a new phrase, a wipe moves left to right: cells
+/// behind it show the incoming phrase with its shimmer ramping up, cells ahead of it keep the
+/// outgoing phrase with its shimmer ramping down.
+///
+/// An empty list renders an empty line and a single phrase never rotates.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::text::Line;
+/// use tui_shimmer::PhraseRotator;
+///
+/// fn text(line: Line) -> String {
+///     line.spans.iter().map(|span| span.content.as_ref()).collect()
+/// }
+///
+/// let mut rotator = PhraseRotator::new(["Connecting", "Downloading"])
+///     .dwell(Duration::from_secs(2))
+///     .crossfade(Duration::from_millis(400));
+///
+/// rotator.tick(Duration::from_millis(1999));
+/// assert_eq!(rotator.current(), Some("Connecting"));
+/// assert_eq!(text(rotator.line()), "Connecting");
+///
+/// // Halfway through the crossfade the wipe has uncovered the first 6 of 11 cells: the
+/// // incoming phrase behind it, the rest of the outgoing phrase ahead of it.
+/// rotator.tick(Duration::from_millis(201));
+/// assert_eq!(rotator.current(), Some("Downloading"));
+/// assert_eq!(text(rotator.line()), "Downloting ");
+///
+/// rotator.tick(Duration::from_millis(300));
+/// assert_eq!(text(rotator.line()), "Downloading");
+///
+/// // The list wraps back to the first phrase.
+/// rotator.tick(Duration::from_millis(1600));
+/// assert_eq!(rotator.current(), Some("Connecting"));
+///
+/// // Empty lists render nothing and single phrases never rotate.
+/// let mut empty = PhraseRotator::new(Vec::<String>::new());
+/// let mut single = PhraseRotator::new(["Waiting"]).dwell(Duration::from_secs(1));
+/// empty.tick(Duration::from_secs(5));
+/// single.tick(Duration::from_millis(1100));
+/// assert_eq!(empty.current(), None);
+/// assert_eq!(empty.line(), Line::default());
+/// assert_eq!(single.current(), Some("Waiting"));
+/// assert_eq!(text(single.line()), "Waiting");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PhraseRotator {
+    phrases: Vec<String>,
+    dwell: Duration,
+    crossfade: Duration,
+    style: Style,
+    config: ShimmerConfig,
+    elapsed: Duration,
+}
+
+impl PhraseRotator {
+    /// Creates a rotator over `phrases`, starting at the first one.
+    pub fn new<I, S>(phrases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            phrases: phrases.into_iter().map(Into::into).collect(),
+            dwell: DEFAULT_DWELL,
+            crossfade: DEFAULT_CROSSFADE,
+            style: Style::default(),
+            config: ShimmerConfig::default(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Sets how long each phrase stays on screen. Defaults to 3 seconds.
+    #[must_use]
+    pub fn dwell(mut self, dwell: Duration) -> Self {
+        self.dwell = dwell;
+        self
+    }
+
+    /// Sets the length of the wipe between phrases. Defaults to 300 ms.
+    ///
+    /// The crossfade never exceeds the dwell time.
+    #[must_use]
+    pub fn crossfade(mut self, crossfade: Duration) -> Self {
+        self.crossfade = crossfade;
+        self
+    }
+
+    /// Sets the base style of every phrase.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the shimmer configuration used for every phrase.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Advances the rotation and the shimmer by `dt`, scaled by the process-wide
+    /// [`time_scale`].
+    pub fn tick(&mut self, dt: Duration) {
+        self.elapsed = self.elapsed.saturating_add(dt.mul_f32(time_scale()));
+    }
+
+    /// Returns the phrase currently being shown, or `None` for an empty list.
+    ///
+    /// During a crossfade this is the incoming phrase.
+    pub fn current(&self) -> Option<&str> {
+        self.phrases.get(self.position().0).map(String::as_str)
+    }
+
+    /// Renders the current frame.
+    pub fn line(&self) -> Line<'static> {
+        let phase = self.shimmer_phase();
+        match self.phrases.as_slice() {
+            [] => Line::default(),
+            [phrase] => Line::from(shimmer_spans_with_config(
+                phrase,
+                self.style,
+                phase,
+                &self.config,
+            )),
+            phrases => {
+                let (index, progress) = self.position();
+                match progress {
+                    Some(progress) => {
+                        let outgoing = &phrases[(index + phrases.len() - 1) % phrases.len()];
+                        Line::from(self.wipe(outgoing, &phrases[index], progress, phase))
+                    }
+                    None => Line::from(shimmer_spans_with_config(
+                        &phrases[index],
+                        self.style,
+                        phase,
+                        &self.config,
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Index of the current phrase and, while crossfading into it, the wipe progress (0.0..1.0).
+    fn position(&self) -> (usize, Option<f32>) {
+        if self.phrases.len() < 2 {
+            return (0, None);
+        }
+        let dwell = self.dwell.max(Duration::from_millis(1));
+        let cycle = self.elapsed.as_nanos() / dwell.as_nanos();
+        let index = (cycle % self.phrases.len() as u128) as usize;
+        let into_phrase = self.elapsed.as_nanos() - cycle * dwell.as_nanos();
+        let crossfade = self.crossfade.min(dwell).as_nanos();
+        if cycle == 0 || into_phrase >= crossfade {
+            (index, None)
+        } else {
+            (index, Some(into_phrase as f32 / crossfade as f32))
+        }
+    }
+
+    fn shimmer_phase(&self) -> f32 {
+        (self.elapsed.as_secs_f32() / SHIMMER_SWEEP_SECONDS).rem_euclid(1.0)
+    }
+
+    fn wipe(
+        &self,
+        outgoing: &str,
+        incoming: &str,
+        progress: f32,
+        phase: f32,
+    ) -> Vec<Span<'static>> {
+        let outgoing: Vec<char> = outgoing.chars().collect();
+        let incoming: Vec<char> = incoming.chars().collect();
+        let width = outgoing.len().max(incoming.len());
+        let wipe_col = (progress * width as f32).round() as usize;
+        let painter = CellPainter::new(self.style, &self.config);
+
+        let cells = (0..width).map(|col| {
+            let (chars, amplitude) = if col < wipe_col {
+                (&incoming, progress)
+            } else {
+                (&outgoing, 1.0 - progress)
+            };
+            let ch = chars.get(col).copied().unwrap_or(' ');
+            let intensity =
+                intensity_at_with_config(col, chars.len(), phase, &self.config) * amplitude;
+            (ch, painter.style(col, intensity))
+        });
+        coalesce_spans(cells, width)
+    }
+}
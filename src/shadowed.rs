@@ -0,0 +1,132 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::{shimmer_spans_with_config, ShimmerConfig};
+
+/// Shimmering text over a dim copy of itself, offset for a drop-shadow look.
+///
+/// The shadow is drawn first, clipped to the render area, and the shimmering text is drawn
+/// over it at the top-left of the area, so the shadow only shows where the text does not.
+/// The band position comes from [`phase`](ShimmerShadowed::phase); set it every frame.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use ratatui::widgets::Widget;
+/// use tui_shimmer::ShimmerShadowed;
+///
+/// let shadow = Style::default().fg(Color::DarkGray);
+/// let area = Rect::new(0, 0, 8, 2);
+/// let mut buf = Buffer::empty(area);
+/// ShimmerShadowed::new("Hello")
+///     .shadow_style(shadow)
+///     .offset((1, 1))
+///     .phase(0.5)
+///     .render(area, &mut buf);
+///
+/// assert_eq!(buf[(1, 1)].symbol(), "H");
+/// assert_eq!(buf[(1, 1)].style().fg, Some(Color::DarkGray));
+///
+/// // Where the copies overlap, the main text wins.
+/// let mut buf = Buffer::empty(area);
+/// ShimmerShadowed::new("Hello").offset((1, 0)).render(area, &mut buf);
+/// assert_eq!(buf[(1, 0)].symbol(), "e");
+/// assert_eq!(buf[(5, 0)].symbol(), "o");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerShadowed {
+    text: String,
+    base_style: Style,
+    shadow_style: Style,
+    offset: (u16, u16),
+    phase: f32,
+    config: ShimmerConfig,
+}
+
+impl ShimmerShadowed {
+    /// Creates the effect for `text` with a dark gray shadow one cell right and one row down.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            base_style: Style::default(),
+            shadow_style: Style::default().fg(Color::DarkGray),
+            offset: (1, 1),
+            phase: 0.0,
+            config: ShimmerConfig::default(),
+        }
+    }
+
+    /// Sets the base style of the shimmering text.
+    #[must_use]
+    pub fn style(mut self, base_style: Style) -> Self {
+        self.base_style = base_style;
+        self
+    }
+
+    /// Sets the style of the shadow copy. Defaults to a dark gray foreground.
+    #[must_use]
+    pub fn shadow_style(mut self, shadow_style: Style) -> Self {
+        self.shadow_style = shadow_style;
+        self
+    }
+
+    /// Sets how far the shadow sits from the text, as `(columns right, rows down)`.
+    #[must_use]
+    pub fn offset(mut self, offset: (u16, u16)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the band phase (0.0..1.0). Defaults to 0.0.
+    #[must_use]
+    pub fn phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the shimmer configuration of the main text.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl Widget for ShimmerShadowed {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &ShimmerShadowed {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let (dx, dy) = self.offset;
+        let shadow_x = area.x.saturating_add(dx);
+        let shadow_y = area.y.saturating_add(dy);
+        if shadow_x < area.right() && shadow_y < area.bottom() {
+            let width = area.right() - shadow_x;
+            buf.set_stringn(
+                shadow_x,
+                shadow_y,
+                &self.text,
+                usize::from(width),
+                self.shadow_style,
+            );
+        }
+
+        let spans =
+            shimmer_spans_with_config(&self.text, self.base_style, self.phase, &self.config);
+        buf.set_line(area.x, area.y, &Line::from(spans), area.width);
+    }
+}
@@ -0,0 +1,57 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::color::CellPainter;
+use crate::{build_span, ColorMode, ShimmerConfig};
+
+/// Renders the spinner frame at `time_phase` with its color pulsing at `shimmer_phase`.
+///
+/// `frames` is one cycle of spinner glyphs, such as `["⠋", "⠙", "⠹", "⠸"]`, and `time_phase`
+/// (0.0..1.0) picks frame `floor(time_phase * frames.len())`. A band would be too wide for a
+/// glyph or two, so the whole frame pulses instead: its intensity is
+/// `0.5 * (1 - cos(2π * shimmer_phase))`, from the base style at 0.0 to the peak at 0.5,
+/// styled like a band cell of the same intensity. [`ShimmerConfig::min_animated_len`] does
+/// not apply; [`ColorMode::Plain`] renders the frame in the base style.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{shimmer_spinner, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let frames = ["⠋", "⠙", "⠹", "⠸"];
+/// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+///
+/// let dim = shimmer_spinner(&frames, 0.3, 0.0, base, &config);
+/// let lit = shimmer_spinner(&frames, 0.3, 0.5, base, &config);
+/// assert_eq!(dim[0].content, "⠙");
+/// assert_eq!(dim[0].style.fg, Some(Color::Rgb(100, 100, 100)));
+/// assert_eq!(lit[0].content, "⠙");
+/// assert_ne!(lit[0].style, dim[0].style);
+/// assert_eq!(shimmer_spinner(&frames, 0.9, 0.5, base, &config)[0].content, "⠸");
+/// ```
+pub fn shimmer_spinner(
+    frames: &[&str],
+    time_phase: f32,
+    shimmer_phase: f32,
+    base_style: Style,
+    config: &ShimmerConfig,
+) -> Vec<Span<'static>> {
+    if frames.is_empty() {
+        return Vec::new();
+    }
+    let index = (time_phase.rem_euclid(1.0) * frames.len() as f32) as usize;
+    let frame = frames[index.min(frames.len() - 1)];
+    if frame.is_empty() {
+        return Vec::new();
+    }
+
+    let mode = config.render_mode();
+    if mode == ColorMode::Plain {
+        return vec![build_span(frame, base_style)];
+    }
+    let intensity = 0.5 * (1.0 - (std::f32::consts::TAU * shimmer_phase).cos());
+    let painter = CellPainter::with_mode(base_style, config, mode);
+    vec![build_span(frame, painter.style(0, intensity))]
+}
@@ -0,0 +1,471 @@
+use std::mem;
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::band::band_period;
+use crate::color::wash_style;
+use crate::hover::scaled_spans;
+use crate::{
+    build_span, recommended_min_frame_interval, shimmer_spans_with_config, time_scale,
+    ShimmerConfig, SHIMMER_SWEEP_SECONDS,
+};
+
+mod countdown;
+mod eta;
+mod focus;
+mod reanchor;
+mod render;
+mod smoothing;
+mod stats;
+mod visibility;
+mod wash;
+
+pub use focus::FocusPolicy;
+use render::RenderKey;
+pub use stats::ShimmerStats;
+pub use visibility::HiddenRender;
+
+/// Accumulated shimmer phase driven by frame deltas.
+///
+/// Unlike [`shimmer_spans_with_style`](crate::shimmer_spans_with_style), which reads a global
+/// clock, the phase only moves when [`tick`](ShimmerState::tick) is called, so an indicator can
+/// be paused and resumed without jumping.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::style::Style;
+/// use tui_shimmer::{ShimmerConfig, ShimmerState};
+///
+/// let mut state = ShimmerState::new();
+/// state.tick(Duration::from_millis(500));
+/// state.pause();
+/// state.tick(Duration::from_millis(500));
+/// assert_eq!(state.phase(), 0.25);
+///
+/// let spans = state.spans("Loading...", Style::default(), &ShimmerConfig::default());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShimmerState {
+    phase: PhaseAccumulator,
+    sweep_seconds: f32,
+    paused: bool,
+    use_time_scale: bool,
+    /// Seconds ticked so far, driving the slow [`ShimmerConfig::base_wash`] cycle.
+    wash_elapsed: f64,
+    /// Seconds of band travel ticked so far, after the ETA speed, for
+    /// [`ShimmerConfig::velocity`].
+    travel_elapsed: f64,
+    /// Sweep speed multiplier set from the ETA, 1.0 without one.
+    speed: f32,
+    /// Speed with the whole ETA remaining, speed at zero remaining, and the curve exponent.
+    eta_curve: (f32, f32, f32),
+    /// Bounds on the ETA-derived speed.
+    eta_limits: (f32, f32),
+    /// What [`should_render`](ShimmerState::should_render) last approved.
+    last_render: Option<RenderKey>,
+    focused: bool,
+    focus_policy: FocusPolicy,
+    /// Time ticked while throttled that has not yet moved the phase.
+    throttle_pending: Duration,
+    /// How long the state must run before the shimmer shows.
+    visible_after: Duration,
+    hidden_render: HiddenRender,
+    /// Unpaused time ticked since creation or the last restart, stopping once fully faded in.
+    alive: Duration,
+    /// Largest intensity gain per cell between [`spans_smoothed`](ShimmerState::spans_smoothed)
+    /// frames.
+    max_rise: Option<f32>,
+    /// Hash of the text last rendered by `spans_smoothed`, and the intensities it showed.
+    smoothed: (u64, Vec<f32>),
+    /// Whether [`set_text`](ShimmerState::set_text) keeps the band's column on a resize.
+    reanchor: bool,
+    /// Char count last passed to `set_text`.
+    text_len: Option<usize>,
+    /// Time over which the band shrinks away, from [`countdown`](ShimmerState::countdown).
+    countdown: Option<Duration>,
+    /// Unpaused time ticked since creation or the last restart, stopping at the countdown.
+    countdown_elapsed: Duration,
+}
+
+/// Sweep length of [`ShimmerState::idle`].
+const IDLE_SWEEP_SECONDS: f32 = 10.0;
+const DEFAULT_ETA_CURVE: (f32, f32, f32) = (1.0, 3.0, 1.0);
+const DEFAULT_ETA_LIMITS: (f32, f32) = (0.5, 3.0);
+/// How long a delayed shimmer takes to fade in once visible.
+const VISIBLE_FADE_IN: Duration = Duration::from_millis(150);
+
+/// Storage for the accumulated phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PhaseAccumulator {
+    Float(f32),
+    /// Phase as a fraction of `2^32`, plus the sub-unit remainder (in `2^32 * nanoseconds`
+    /// over the sweep length) carried between ticks so no time is lost to rounding.
+    Fixed {
+        fraction: u32,
+        remainder: u128,
+    },
+}
+
+const FIXED_ONE: u128 = 1 << 32;
+
+impl PhaseAccumulator {
+    fn get(self) -> f32 {
+        match self {
+            PhaseAccumulator::Float(phase) => phase,
+            PhaseAccumulator::Fixed { fraction, .. } => {
+                ((f64::from(fraction) / FIXED_ONE as f64) as f32).rem_euclid(1.0)
+            }
+        }
+    }
+}
+
+/// `dt * factor` like [`Duration::mul_f32`], saturating at [`Duration::MAX`] instead of
+/// panicking. Negative factors count as 0.0.
+fn mul_saturating(dt: Duration, factor: f32) -> Duration {
+    Duration::try_from_secs_f32(dt.as_secs_f32() * factor.max(0.0)).unwrap_or(Duration::MAX)
+}
+
+impl Default for ShimmerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShimmerState {
+    /// Creates a running state at phase 0.0 with the default 2 second sweep.
+    pub fn new() -> Self {
+        Self {
+            phase: PhaseAccumulator::Float(0.0),
+            sweep_seconds: SHIMMER_SWEEP_SECONDS,
+            paused: false,
+            use_time_scale: true,
+            wash_elapsed: 0.0,
+            travel_elapsed: 0.0,
+            speed: 1.0,
+            eta_curve: DEFAULT_ETA_CURVE,
+            eta_limits: DEFAULT_ETA_LIMITS,
+            last_render: None,
+            focused: true,
+            focus_policy: FocusPolicy::Freeze,
+            throttle_pending: Duration::ZERO,
+            visible_after: Duration::ZERO,
+            hidden_render: HiddenRender::Plain,
+            alive: Duration::ZERO,
+            max_rise: None,
+            smoothed: (0, Vec::new()),
+            reanchor: false,
+            text_len: None,
+            countdown: None,
+            countdown_elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Creates a running state with the slow 10 second sweep meant for
+    /// [`ShimmerConfig::idle`].
+    pub fn idle() -> Self {
+        Self::new().sweep_seconds(IDLE_SWEEP_SECONDS)
+    }
+
+    /// Sets the duration of one full sweep in seconds. Non-positive values stop the sweep.
+    #[must_use]
+    pub fn sweep_seconds(mut self, sweep_seconds: f32) -> Self {
+        self.sweep_seconds = sweep_seconds;
+        self
+    }
+
+    /// Ignores the process-wide [`set_time_scale`](crate::set_time_scale) multiplier.
+    #[must_use]
+    pub fn ignore_time_scale(mut self) -> Self {
+        self.use_time_scale = false;
+        self
+    }
+
+    /// Tracks the phase as a fixed-point `u32` fraction of the cycle instead of an `f32`.
+    ///
+    /// Each tick adds an exact integer step and carries the rounding remainder to the next
+    /// one, so the phase does not drift over millions of ticks. Use it for always-on
+    /// indicators; [`phase`](ShimmerState::phase) still reports an `f32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::ShimmerState;
+    ///
+    /// // 10^6 ticks of 7 ms make 7000 s, or 2333⅓ sweeps of 3 s.
+    /// let mut state = ShimmerState::new().sweep_seconds(3.0).ignore_time_scale().fixed_point();
+    /// for _ in 0..1_000_000 {
+    ///     state.tick(Duration::from_millis(7));
+    /// }
+    /// assert!((state.phase() - 1.0 / 3.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn fixed_point(mut self) -> Self {
+        if let PhaseAccumulator::Float(phase) = self.phase {
+            self.phase = PhaseAccumulator::Fixed {
+                fraction: (f64::from(phase) * FIXED_ONE as f64) as u32,
+                remainder: 0,
+            };
+        }
+        self
+    }
+
+    /// Advances the phase by `dt`, unless paused.
+    ///
+    /// `dt` is multiplied by the process-wide [`time_scale`] unless
+    /// [`ignore_time_scale`](ShimmerState::ignore_time_scale) was set, and by the ETA
+    /// [`speed`](ShimmerState::speed). While unfocused the [`FocusPolicy`] decides whether and
+    /// how far the phase moves. Time saturates rather than overflowing, so a huge `dt`, such as
+    /// after a suspend, is safe.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::{FocusPolicy, ShimmerState};
+    ///
+    /// let mut state = ShimmerState::new()
+    ///     .delay_before_visible(Duration::from_millis(300))
+    ///     .countdown(Duration::from_secs(5));
+    /// state.tick(Duration::MAX);
+    /// state.tick(Duration::MAX);
+    /// assert!(state.is_visible());
+    ///
+    /// let mut throttled = ShimmerState::new().focus_policy(FocusPolicy::Throttle(0.001));
+    /// throttled.set_focused(false);
+    /// throttled.tick(Duration::MAX);
+    /// throttled.tick(Duration::MAX);
+    /// ```
+    pub fn tick(&mut self, dt: Duration) {
+        if self.paused {
+            return;
+        }
+        let was_visible = self.is_visible();
+        self.alive = self
+            .alive
+            .saturating_add(dt)
+            .min(self.visible_after.saturating_add(VISIBLE_FADE_IN));
+        if let Some(countdown) = self.countdown {
+            self.countdown_elapsed = self.countdown_elapsed.saturating_add(dt).min(countdown);
+        }
+        if !was_visible {
+            return;
+        }
+        if self.is_focused() {
+            self.throttle_pending = Duration::ZERO;
+            self.advance(dt);
+        } else if let Some(step) = self.focus_policy.step() {
+            self.throttle_pending = self.throttle_pending.saturating_add(dt);
+            if self.throttle_pending >= step {
+                let dt = mem::take(&mut self.throttle_pending);
+                self.advance(dt);
+            }
+        }
+    }
+
+    /// Moves the phase and wash cycle forward by `dt`.
+    fn advance(&mut self, dt: Duration) {
+        let dt = if self.use_time_scale {
+            mul_saturating(dt, time_scale())
+        } else {
+            dt
+        };
+        self.wash_elapsed += dt.as_secs_f64();
+        let dt = mul_saturating(dt, self.speed);
+        self.travel_elapsed += dt.as_secs_f64();
+        if self.sweep_seconds <= 0.0 {
+            return;
+        }
+        match &mut self.phase {
+            PhaseAccumulator::Float(phase) => {
+                *phase = (*phase + dt.as_secs_f32() / self.sweep_seconds).rem_euclid(1.0);
+            }
+            PhaseAccumulator::Fixed {
+                fraction,
+                remainder,
+            } => {
+                let sweep_nanos = ((f64::from(self.sweep_seconds) * 1e9).round() as u128).max(1);
+                let scaled = dt.as_nanos() * FIXED_ONE + *remainder;
+                *remainder = scaled % sweep_nanos;
+                // Truncating to u32 wraps the step into the current cycle.
+                *fraction = fraction.wrapping_add((scaled / sweep_nanos) as u32);
+            }
+        }
+    }
+
+    /// Current phase in `0.0..1.0`.
+    ///
+    /// This ignores [`ShimmerConfig::velocity`]; see [`phase_for`](ShimmerState::phase_for).
+    pub fn phase(&self) -> f32 {
+        self.phase.get()
+    }
+
+    /// Phase (0.0..1.0) at which `text` renders under `config`.
+    ///
+    /// Equal to [`phase`](ShimmerState::phase) unless `config` sets a
+    /// [`velocity`](ShimmerConfig::velocity), in which case the time ticked so far is turned
+    /// into a band position for the length of `text`.
+    pub fn phase_for(&self, text: &str, config: &ShimmerConfig) -> f32 {
+        self.phase_for_count(text.chars().count(), config)
+    }
+
+    fn phase_for_count(&self, char_count: usize, config: &ShimmerConfig) -> f32 {
+        let period = band_period(char_count, config);
+        match config.velocity {
+            Some(velocity) if velocity.is_finite() && period > 0 => {
+                (self.travel_elapsed * f64::from(velocity) / period as f64).rem_euclid(1.0) as f32
+            }
+            _ => self.phase(),
+        }
+    }
+
+    fn set_phase(&mut self, phase: f32) {
+        self.phase = match self.phase {
+            PhaseAccumulator::Float(_) => PhaseAccumulator::Float(phase),
+            PhaseAccumulator::Fixed { .. } => PhaseAccumulator::Fixed {
+                fraction: (f64::from(phase) * FIXED_ONE as f64) as u32,
+                remainder: 0,
+            },
+        };
+    }
+
+    /// Freezes the phase; [`tick`](ShimmerState::tick) is ignored until resumed.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Continues from the phase held when paused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::ShimmerState;
+    ///
+    /// let mut state = ShimmerState::new().ignore_time_scale();
+    /// let mut uninterrupted = state.clone();
+    /// state.tick(Duration::from_millis(500));
+    /// let held = state.phase();
+    ///
+    /// state.pause();
+    /// for _ in 0..10 {
+    ///     state.tick(Duration::from_millis(300));
+    /// }
+    /// assert_eq!(state.phase(), held);
+    ///
+    /// // The first tick after resuming moves on from the held phase by that tick alone,
+    /// // as if the pause had never happened.
+    /// state.resume();
+    /// state.tick(Duration::from_millis(100));
+    /// uninterrupted.tick(Duration::from_millis(500));
+    /// uninterrupted.tick(Duration::from_millis(100));
+    /// assert!((state.phase() - (held + 0.05)).abs() < 1e-6);
+    /// assert_eq!(state.phase(), uninterrupted.phase());
+    /// ```
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Whether the state is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns to phase 0.0 and re-arms
+    /// [`delay_before_visible`](ShimmerState::delay_before_visible), keeping the pause state.
+    pub fn restart(&mut self) {
+        self.set_phase(0.0);
+        self.wash_elapsed = 0.0;
+        self.travel_elapsed = 0.0;
+        self.throttle_pending = Duration::ZERO;
+        self.alive = Duration::ZERO;
+        self.countdown_elapsed = Duration::ZERO;
+        self.last_render = None;
+        self.smoothed.1.clear();
+    }
+
+    /// Suggested time until the next redraw of `text`, given the current focus and pause state.
+    ///
+    /// While focused this is the [`recommended_min_frame_interval`] for the sweep speed. While
+    /// throttled it is at least one throttled step, and while frozen or paused it is
+    /// [`Duration::MAX`], since nothing moves until focus returns or the state resumes; the
+    /// same goes once a [`countdown`](ShimmerState::countdown) has run out. While hidden by
+    /// [`delay_before_visible`](ShimmerState::delay_before_visible) it is the time left until
+    /// the shimmer shows.
+    pub fn frame_interval(&self, text: &str, config: &ShimmerConfig) -> Duration {
+        if self.paused || self.countdown_config(config).is_none() {
+            return Duration::MAX;
+        }
+        if !self.is_visible() {
+            return self.visible_after - self.alive;
+        }
+        let char_count = text.chars().count();
+        let interval = recommended_min_frame_interval(
+            char_count,
+            self.effective_sweep(char_count, config),
+            config,
+        );
+        if self.is_focused() {
+            return interval;
+        }
+        self.focus_policy
+            .step()
+            .map_or(Duration::MAX, |step| interval.max(step))
+    }
+
+    /// Seconds per sweep of `char_count` chars after the ETA speed and any
+    /// [`ShimmerConfig::velocity`], or 0.0 when the sweep does not move.
+    fn effective_sweep(&self, char_count: usize, config: &ShimmerConfig) -> f32 {
+        let sweep_seconds = match config.velocity {
+            Some(velocity) if velocity != 0.0 => {
+                band_period(char_count, config) as f32 / velocity.abs()
+            }
+            Some(_) => 0.0,
+            None => self.sweep_seconds,
+        };
+        if self.speed > 0.0 {
+            sweep_seconds / self.speed
+        } else {
+            0.0
+        }
+    }
+
+    /// Renders `text` at the current phase, with the base color washed by
+    /// [`ShimmerConfig::base_wash`] if set.
+    ///
+    /// While hidden by [`delay_before_visible`](ShimmerState::delay_before_visible), renders
+    /// as set by [`hidden_render`](ShimmerState::hidden_render) instead.
+    pub fn spans(
+        &self,
+        text: &str,
+        base_style: Style,
+        config: &ShimmerConfig,
+    ) -> Vec<Span<'static>> {
+        let base_style = wash_style(base_style, config, self.wash_phase(config));
+        let phase = self.phase_for(text, config);
+        let Some(config) = self.countdown_config(config) else {
+            return match text {
+                "" => Vec::new(),
+                text => vec![build_span(text, base_style)],
+            };
+        };
+        let config = config.as_ref();
+        match self.visibility() {
+            _ if text.is_empty() => Vec::new(),
+            0.0 => match self.hidden_render {
+                HiddenRender::Plain => vec![build_span(text, base_style)],
+                HiddenRender::Empty => Vec::new(),
+            },
+            1.0 => shimmer_spans_with_config(text, base_style, phase, config),
+            visibility => scaled_spans(text, base_style, phase, config, visibility),
+        }
+    }
+}
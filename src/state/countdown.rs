@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use super::ShimmerState;
+use crate::ShimmerConfig;
+
+impl ShimmerState {
+    /// Shrinks the band to nothing over `duration`, for a timer that visibly runs out.
+    ///
+    /// The half-widths, including [`leading_width`](ShimmerConfig::leading_width) and
+    /// [`trailing_width`](ShimmerConfig::trailing_width), scale linearly with the share of
+    /// `duration` left, rounded to whole cells. Once it has passed the text renders in its base
+    /// style. Unpaused ticks count toward it at their real length, ignoring the time scale and
+    /// ETA speed; [`restart`](ShimmerState::restart) starts it over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{ColorMode, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+    /// let base = Style::default().fg(Color::Rgb(100, 100, 100));
+    /// let text = "Offer expires soon..";
+    /// let lit = |state: &ShimmerState| {
+    ///     let spans = state.spans(text, base, &config);
+    ///     let styles: Vec<Style> = spans
+    ///         .iter()
+    ///         .flat_map(|span| span.content.chars().map(|_| span.style))
+    ///         .collect();
+    ///     styles.iter().filter(|style| **style != styles[0]).count()
+    /// };
+    /// let mut full = ShimmerState::new().ignore_time_scale();
+    /// let mut timer = ShimmerState::new().ignore_time_scale().countdown(Duration::from_secs(2));
+    ///
+    /// // Halfway, the band is centered on the text at half its half-width (5 cells to 3).
+    /// full.tick(Duration::from_secs(1));
+    /// timer.tick(Duration::from_secs(1));
+    /// assert_eq!(lit(&full), 9);
+    /// assert_eq!(lit(&timer), 5);
+    ///
+    /// timer.tick(Duration::from_secs(1));
+    /// assert_eq!(timer.spans(text, base, &config), vec![Span::styled(text, base)]);
+    /// ```
+    #[must_use]
+    pub fn countdown(mut self, duration: Duration) -> Self {
+        self.countdown = Some(duration);
+        self
+    }
+
+    /// `config` with the band narrowed by the [`countdown`](ShimmerState::countdown), or `None`
+    /// once it has run out.
+    pub(super) fn countdown_config<'a>(
+        &self,
+        config: &'a ShimmerConfig,
+    ) -> Option<Cow<'a, ShimmerConfig>> {
+        let Some(countdown) = self.countdown else {
+            return Some(Cow::Borrowed(config));
+        };
+        if self.countdown_elapsed >= countdown {
+            return None;
+        }
+        let left = 1.0 - self.countdown_elapsed.as_secs_f32() / countdown.as_secs_f32();
+        let scale = |width: usize| (width as f32 * left).round() as usize;
+        Some(Cow::Owned(ShimmerConfig {
+            band_half_width: scale(config.band_half_width),
+            leading_width: config.leading_width.map(scale),
+            trailing_width: config.trailing_width.map(scale),
+            ..config.clone()
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use super::ShimmerState;
+
+impl ShimmerState {
+    /// Sets how [`set_eta`](ShimmerState::set_eta) maps the remaining time to a sweep speed.
+    ///
+    /// The speed is `start + (end - start) * done.powf(exponent)`, where `done` runs from 0.0
+    /// with the whole ETA remaining to 1.0 when it is reached. Defaults to `(1.0, 3.0, 1.0)`:
+    /// a linear speed-up to 3x. Use an `end` below `start` to slow down instead.
+    #[must_use]
+    pub fn eta_curve(mut self, start: f32, end: f32, exponent: f32) -> Self {
+        self.eta_curve = (start, end, exponent);
+        self
+    }
+
+    /// Bounds the ETA-derived speed to `min..=max`. Defaults to `0.5..=3.0`.
+    #[must_use]
+    pub fn eta_limits(mut self, min: f32, max: f32) -> Self {
+        self.eta_limits = (min, max.max(min));
+        self
+    }
+
+    /// Adjusts the sweep speed to how much of `total` is still `remaining`.
+    ///
+    /// The new speed applies from the next [`tick`](ShimmerState::tick); the accumulated phase
+    /// carries over, so updating the ETA never makes the band jump. A zero `total` counts as
+    /// no time remaining.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::ShimmerState;
+    ///
+    /// let mut state = ShimmerState::new().sweep_seconds(2.0).ignore_time_scale();
+    /// let total = Duration::from_secs(100);
+    /// for (remaining, speed) in [(100, 1.0), (75, 1.5), (50, 2.0), (25, 2.5), (0, 3.0)] {
+    ///     state.set_eta(Duration::from_secs(remaining), total);
+    ///     assert_eq!(state.speed(), speed);
+    /// }
+    ///
+    /// // Changing the speed keeps the phase; only later ticks run faster.
+    /// state.clear_eta();
+    /// state.tick(Duration::from_millis(500));
+    /// let before = state.phase();
+    /// state.set_eta(Duration::from_secs(50), total);
+    /// assert_eq!(state.phase(), before);
+    /// state.tick(Duration::from_millis(200));
+    /// assert!((state.phase() - (before + 0.2)).abs() < 1e-5);
+    /// state.clear_eta();
+    /// assert_eq!(state.speed(), 1.0);
+    /// assert!((state.phase() - (before + 0.2)).abs() < 1e-5);
+    ///
+    /// // A falling curve slows down, but never below the lower limit.
+    /// let mut slowing = ShimmerState::new().eta_curve(1.0, 0.2, 1.0);
+    /// slowing.set_eta(Duration::ZERO, total);
+    /// assert_eq!(slowing.speed(), 0.5);
+    /// ```
+    pub fn set_eta(&mut self, remaining: Duration, total: Duration) {
+        let remaining = if total.is_zero() {
+            0.0
+        } else {
+            (remaining.as_secs_f32() / total.as_secs_f32()).clamp(0.0, 1.0)
+        };
+        let (start, end, exponent) = self.eta_curve;
+        let speed = start + (end - start) * (1.0 - remaining).powf(exponent);
+        let (min, max) = self.eta_limits;
+        self.speed = if speed.is_finite() {
+            speed.clamp(min, max)
+        } else {
+            1.0
+        };
+    }
+
+    /// Returns to the base sweep speed, keeping the current phase.
+    pub fn clear_eta(&mut self) {
+        self.speed = 1.0;
+    }
+
+    /// Current sweep speed multiplier; 1.0 unless an ETA is set.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
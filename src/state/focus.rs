@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use super::ShimmerState;
+use crate::is_focused;
+
+/// What a [`ShimmerState`] does while the terminal is unfocused.
+///
+/// Set per state with [`ShimmerState::focus_policy`]; focus is reported with
+/// [`ShimmerState::set_focused`] or the process-wide [`set_focused`](crate::set_focused).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FocusPolicy {
+    /// Holds the current frame until focus returns.
+    #[default]
+    Freeze,
+    /// Keeps sweeping at this many frames per second: the phase only advances once a whole
+    /// frame of ticks has built up. A non-positive or non-finite rate freezes instead.
+    Throttle(f32),
+}
+
+impl FocusPolicy {
+    /// Ticked time that makes up one throttled step, or `None` when frozen.
+    pub(super) fn step(self) -> Option<Duration> {
+        match self {
+            FocusPolicy::Freeze => None,
+            FocusPolicy::Throttle(fps) if fps > 0.0 && fps.is_finite() => {
+                Duration::try_from_secs_f32(1.0 / fps).ok()
+            }
+            FocusPolicy::Throttle(_) => None,
+        }
+    }
+}
+
+impl ShimmerState {
+    /// Sets what the state does while unfocused. Defaults to [`FocusPolicy::Freeze`].
+    #[must_use]
+    pub fn focus_policy(mut self, focus_policy: FocusPolicy) -> Self {
+        self.focus_policy = focus_policy;
+        self
+    }
+
+    /// Reports whether the terminal has focus; while it does not, ticks follow the
+    /// [`FocusPolicy`].
+    ///
+    /// Regaining focus continues from the phase shown while unfocused, dropping any throttled
+    /// time that had not yet been shown, so the band never jumps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::{FocusPolicy, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig::default();
+    /// // 20 chars plus 20 cells of padding, swept in 4 seconds.
+    /// let text = "Indexing workspace..";
+    /// let mut state = ShimmerState::new().sweep_seconds(4.0).ignore_time_scale();
+    /// assert_eq!(state.frame_interval(text, &config), Duration::from_millis(100));
+    ///
+    /// // Frozen: the phase holds and no redraw is needed.
+    /// state.set_focused(false);
+    /// state.tick(Duration::from_secs(1));
+    /// assert_eq!(state.phase(), 0.0);
+    /// assert_eq!(state.frame_interval(text, &config), Duration::MAX);
+    ///
+    /// // Throttled to 2 fps: the phase moves in half-second steps.
+    /// let mut state = state.focus_policy(FocusPolicy::Throttle(2.0));
+    /// assert_eq!(state.frame_interval(text, &config), Duration::from_millis(500));
+    /// state.tick(Duration::from_millis(400));
+    /// assert_eq!(state.phase(), 0.0);
+    /// state.tick(Duration::from_millis(100));
+    /// assert_eq!(state.phase(), 0.125);
+    ///
+    /// // Refocusing drops the unshown 300 ms and carries on smoothly.
+    /// state.tick(Duration::from_millis(300));
+    /// state.set_focused(true);
+    /// state.tick(Duration::from_millis(500));
+    /// assert_eq!(state.phase(), 0.25);
+    /// ```
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Whether this state counts as focused: its own
+    /// [`set_focused`](ShimmerState::set_focused) and the process-wide
+    /// [`set_focused`](crate::set_focused) both report focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused && is_focused()
+    }
+}
@@ -0,0 +1,77 @@
+use super::ShimmerState;
+use crate::band::band_period;
+use crate::ShimmerConfig;
+
+impl ShimmerState {
+    /// Keeps the band on the same column when [`set_text`](ShimmerState::set_text) reports a
+    /// new text length.
+    ///
+    /// The band crosses the text and its padding once per sweep, so a label that grows or
+    /// shrinks, such as a counter, changes how far each phase step moves and the band jumps.
+    /// With this set, the phase is re-derived from the band's column and the new length. A
+    /// band past the end of a shortened text restarts its sweep. Defaults to off.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::{band_geometry, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig::default();
+    /// let peak = |state: &ShimmerState, text: &str| {
+    ///     band_geometry(text, state.phase_for(text, &config), &config).peak_col
+    /// };
+    /// let mut anchored = ShimmerState::new().ignore_time_scale().reanchor_on_resize();
+    /// let mut plain = ShimmerState::new().ignore_time_scale();
+    /// for state in [&mut anchored, &mut plain] {
+    ///     state.set_text("9 s..", &config);
+    ///     state.tick(Duration::from_millis(970));
+    ///     assert_eq!(peak(state, "9 s.."), 2.0);
+    ///     state.set_text("10 s...", &config);
+    /// }
+    ///
+    /// assert_eq!(peak(&anchored, "10 s..."), 2.0);
+    /// assert_eq!(peak(&plain, "10 s..."), 3.0);
+    /// ```
+    #[must_use]
+    pub fn reanchor_on_resize(mut self) -> Self {
+        self.reanchor = true;
+        self
+    }
+
+    /// Reports the text about to be rendered, once per frame before rendering it.
+    ///
+    /// With [`reanchor_on_resize`](ShimmerState::reanchor_on_resize), a change in its length
+    /// moves the phase so the band stays on the same column; otherwise this only records the
+    /// length.
+    pub fn set_text(&mut self, text: &str, config: &ShimmerConfig) {
+        let char_count = text.chars().count();
+        let Some(previous) = self.text_len.replace(char_count) else {
+            return;
+        };
+        if !self.reanchor || previous == char_count {
+            return;
+        }
+        let (from, to) = (
+            band_period(previous, config),
+            band_period(char_count, config),
+        );
+        if to == 0 {
+            return;
+        }
+        let exact = self.phase_for_count(previous, config) * from as f32;
+        let phase = if exact < to as f32 {
+            exact / to as f32
+        } else {
+            0.0
+        };
+        match config.velocity {
+            Some(velocity) if velocity.is_finite() && velocity != 0.0 => {
+                self.travel_elapsed = f64::from(phase) * to as f64 / f64::from(velocity);
+            }
+            _ => self.set_phase(phase),
+        }
+        self.last_render = None;
+    }
+}
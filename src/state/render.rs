@@ -0,0 +1,80 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use ratatui_core::style::{Color, Style};
+
+use super::ShimmerState;
+use crate::band::Band;
+use crate::color::wash_style;
+use crate::{ColorMode, ShimmerConfig};
+
+/// Everything that changes a render between frames of the same text and config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct RenderKey {
+    text_hash: u64,
+    position: (isize, f32),
+    color_mode: ColorMode,
+    washed_fg: Option<Color>,
+    visibility: f32,
+    /// Leading and trailing half-widths after the countdown, or `None` once it has run out.
+    band_widths: Option<(usize, usize)>,
+}
+
+impl ShimmerState {
+    /// Whether rendering `text` now would differ from the frame last approved by this method.
+    ///
+    /// Returns `false` while the band stays on the same cell (or sub-cell position, with
+    /// [`ShimmerConfig::sub_cell`]) and the resolved colors are unchanged, so the caller can
+    /// skip building and diffing spans altogether. A `true` result is remembered as the
+    /// rendered frame. Changes to the base style, or to `config` beyond the color mode and band
+    /// geometry, are not detected; call [`invalidate`](ShimmerState::invalidate) after them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use tui_shimmer::{ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig::default();
+    /// // 10 chars plus 20 cells of padding, swept in 30 seconds: one cell per second.
+    /// let mut state = ShimmerState::new().sweep_seconds(30.0).ignore_time_scale();
+    /// assert!(state.should_render("Loading...", &config));
+    ///
+    /// state.tick(Duration::from_millis(100));
+    /// assert!(!state.should_render("Loading...", &config));
+    /// state.tick(Duration::from_millis(100));
+    /// assert!(!state.should_render("Loading...", &config));
+    ///
+    /// state.tick(Duration::from_secs(1));
+    /// assert!(state.should_render("Loading...", &config));
+    /// ```
+    pub fn should_render(&mut self, text: &str, config: &ShimmerConfig) -> bool {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let char_count = text.chars().count();
+        let band_widths = self.countdown_config(config).map(|config| {
+            let width = |side: Option<usize>| side.unwrap_or(config.band_half_width);
+            (width(config.leading_width), width(config.trailing_width))
+        });
+        let key = RenderKey {
+            text_hash: hasher.finish(),
+            position: Band::new(char_count, self.phase_for_count(char_count, config), config)
+                .position(),
+            color_mode: config.render_mode(),
+            washed_fg: wash_style(Style::default(), config, self.wash_phase(config)).fg,
+            visibility: self.visibility(),
+            band_widths,
+        };
+        if self.last_render == Some(key) {
+            return false;
+        }
+        self.last_render = Some(key);
+        true
+    }
+
+    /// Forgets the last approved frame, so the next
+    /// [`should_render`](ShimmerState::should_render) returns `true`.
+    pub fn invalidate(&mut self) {
+        self.last_render = None;
+    }
+}
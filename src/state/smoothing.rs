@@ -0,0 +1,108 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use super::ShimmerState;
+use crate::band::Band;
+use crate::color::{wash_style, CellPainter};
+use crate::{coalesce_spans, is_animated, ShimmerConfig};
+
+impl ShimmerState {
+    /// Limits how much a cell's intensity may grow from one
+    /// [`spans_smoothed`](ShimmerState::spans_smoothed) frame to the next.
+    ///
+    /// At high sweep speeds the band's leading edge can jump several cells between frames and
+    /// pop on at full strength; with a limit, cells brighten over a few frames instead.
+    /// Intensities may still fall by any amount. Defaults to no limit.
+    #[must_use]
+    pub fn max_rise_per_frame(mut self, max_rise: f32) -> Self {
+        self.max_rise = Some(max_rise.max(0.0));
+        self
+    }
+
+    /// Renders `text` like [`spans`](ShimmerState::spans) as the next frame, with each cell's
+    /// intensity rising by at most [`max_rise_per_frame`](ShimmerState::max_rise_per_frame)
+    /// since the previous call.
+    ///
+    /// The intensities shown are kept in the state, so call this once per drawn frame. A
+    /// different text starts again from the base style. Without a limit this is the same as
+    /// [`spans`](ShimmerState::spans). Like the delayed fade-in, smoothed frames paint the
+    /// band's colors only, without [`ShimmerConfig::shadow`] or
+    /// [`ShimmerConfig::band_transform`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::Style;
+    /// use tui_shimmer::{intensity_at_with_config, ColorMode, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+    /// let text = "Loading the index...";
+    /// let mut state = ShimmerState::new().max_rise_per_frame(0.25).ignore_time_scale();
+    /// state.spans_smoothed(text, Style::default(), &config);
+    /// assert_eq!(state.smoothed_intensities()[5], 0.0);
+    ///
+    /// // The band center jumps straight onto cell 5 but the cell brightens over four frames.
+    /// state.tick(Duration::from_millis(750));
+    /// assert_eq!(intensity_at_with_config(5, 20, state.phase(), &config), 1.0);
+    /// let mut shown = Vec::new();
+    /// for _ in 0..5 {
+    ///     state.spans_smoothed(text, Style::default(), &config);
+    ///     shown.push(state.smoothed_intensities()[5]);
+    /// }
+    /// assert_eq!(shown, [0.25, 0.5, 0.75, 1.0, 1.0]);
+    /// ```
+    pub fn spans_smoothed(
+        &mut self,
+        text: &str,
+        base_style: Style,
+        config: &ShimmerConfig,
+    ) -> Vec<Span<'static>> {
+        let visibility = self.visibility();
+        let Some(max_rise) = self.max_rise else {
+            return self.spans(text, base_style, config);
+        };
+        let countdown_config = self.countdown_config(config);
+        let Some(config) = countdown_config.as_deref() else {
+            self.smoothed.1.clear();
+            return self.spans(text, base_style, config);
+        };
+        if visibility == 0.0 || text.is_empty() || !is_animated(text, config) {
+            self.smoothed.1.clear();
+            return self.spans(text, base_style, config);
+        }
+
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let text_hash = hasher.finish();
+        let char_count = text.chars().count();
+        let band = Band::new(char_count, self.phase_for_count(char_count, config), config);
+        let (last_hash, shown) = &mut self.smoothed;
+        if *last_hash != text_hash || shown.len() != char_count {
+            *last_hash = text_hash;
+            *shown = vec![0.0; char_count];
+        }
+        for (index, intensity) in shown.iter_mut().enumerate() {
+            *intensity = (band.intensity(index) * visibility).min(*intensity + max_rise);
+        }
+
+        let base_style = wash_style(base_style, config, self.wash_phase(config));
+        let painter = CellPainter::new(base_style, config);
+        let cells = text
+            .chars()
+            .zip(&self.smoothed.1)
+            .enumerate()
+            .map(|(index, (ch, &intensity))| (ch, painter.style(index, intensity)));
+        coalesce_spans(cells, char_count)
+    }
+
+    /// Per-character intensities (0.0..=1.0) shown by the last
+    /// [`spans_smoothed`](ShimmerState::spans_smoothed) frame; empty when it rendered without
+    /// the band.
+    pub fn smoothed_intensities(&self) -> &[f32] {
+        &self.smoothed.1
+    }
+}
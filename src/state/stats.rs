@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use super::ShimmerState;
+use crate::band::band_period;
+use crate::{band_geometry, recommended_min_frame_interval, ColorMode, ShimmerConfig};
+
+impl ShimmerState {
+    /// Like [`spans`](ShimmerState::spans), also describing the frame that was rendered.
+    ///
+    /// The stats are taken from the same render, so a debug readout built from them shows
+    /// exactly what is on screen.
+    pub fn spans_with_stats(
+        &self,
+        text: &str,
+        base_style: Style,
+        config: &ShimmerConfig,
+    ) -> (Vec<Span<'static>>, ShimmerStats) {
+        let spans = self.spans(text, base_style, config);
+        let char_count = text.chars().count();
+        let phase = self.phase_for_count(char_count, config);
+        let stats = ShimmerStats {
+            phase,
+            period: band_period(char_count, config),
+            peak_col: band_geometry(text, phase, config).peak_col,
+            span_count: spans.len(),
+            color_mode: config.render_mode(),
+            frame_interval: recommended_min_frame_interval(
+                char_count,
+                self.effective_sweep(char_count, config),
+                config,
+            ),
+        };
+        (spans, stats)
+    }
+}
+
+/// What a single [`ShimmerState::spans_with_stats`] render did, for tuning and debugging.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShimmerStats {
+    /// Phase the frame was rendered at.
+    pub phase: f32,
+    /// Band positions per sweep: the char count, or the
+    /// [`active_range`](ShimmerConfig::active_range) length, plus both paddings.
+    pub period: usize,
+    /// Display column of the band center relative to the first char; see
+    /// [`BandGeometry::peak_col`](crate::BandGeometry::peak_col).
+    pub peak_col: f32,
+    /// Number of spans emitted.
+    pub span_count: usize,
+    /// The color path in use; never [`ColorMode::Auto`].
+    pub color_mode: ColorMode,
+    /// Longest redraw interval that still shows every band position; see
+    /// [`recommended_min_frame_interval`].
+    pub frame_interval: Duration,
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use super::{ShimmerState, VISIBLE_FADE_IN};
+
+/// What [`ShimmerState::spans`] returns before
+/// [`delay_before_visible`](ShimmerState::delay_before_visible) has passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HiddenRender {
+    /// The text in its base style, without the band.
+    #[default]
+    Plain,
+    /// No spans at all.
+    Empty,
+}
+
+impl ShimmerState {
+    /// Keeps the shimmer hidden until the state has been ticked, unpaused, for `delay`.
+    ///
+    /// Operations that finish within the delay never flash an animation: until then
+    /// [`spans`](ShimmerState::spans) renders as set by
+    /// [`hidden_render`](ShimmerState::hidden_render) and the phase holds at 0.0. Once the
+    /// delay has passed the band starts from phase 0.0 and fades in over 150 ms.
+    /// [`restart`](ShimmerState::restart) re-arms the delay. Defaults to no delay.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::Style;
+    /// use ratatui::text::Span;
+    /// use tui_shimmer::{ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig::default();
+    /// let mut state = ShimmerState::new()
+    ///     .delay_before_visible(Duration::from_millis(300))
+    ///     .ignore_time_scale();
+    ///
+    /// state.tick(Duration::from_millis(299));
+    /// assert!(!state.is_visible());
+    /// assert_eq!(state.phase(), 0.0);
+    /// assert_eq!(
+    ///     state.spans("Saving", Style::default(), &config),
+    ///     vec![Span::raw("Saving")],
+    /// );
+    ///
+    /// state.tick(Duration::from_millis(2));
+    /// assert!(state.is_visible());
+    /// assert_eq!(state.phase(), 0.0);
+    ///
+    /// state.restart();
+    /// assert!(!state.is_visible());
+    /// ```
+    #[must_use]
+    pub fn delay_before_visible(mut self, delay: Duration) -> Self {
+        self.visible_after = delay;
+        self
+    }
+
+    /// Sets what [`spans`](ShimmerState::spans) returns while hidden by
+    /// [`delay_before_visible`](ShimmerState::delay_before_visible). Defaults to
+    /// [`HiddenRender::Plain`].
+    #[must_use]
+    pub fn hidden_render(mut self, hidden_render: HiddenRender) -> Self {
+        self.hidden_render = hidden_render;
+        self
+    }
+
+    /// Whether [`delay_before_visible`](ShimmerState::delay_before_visible) has passed, so the
+    /// band is shown.
+    pub fn is_visible(&self) -> bool {
+        self.alive >= self.visible_after
+    }
+
+    /// Band strength: 0.0 while hidden, rising to 1.0 over the fade-in after the delay.
+    pub(super) fn visibility(&self) -> f32 {
+        if !self.is_visible() {
+            0.0
+        } else if self.visible_after.is_zero() {
+            1.0
+        } else {
+            ((self.alive - self.visible_after).as_secs_f32() / VISIBLE_FADE_IN.as_secs_f32())
+                .min(1.0)
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::ShimmerState;
+use crate::ShimmerConfig;
+
+impl ShimmerState {
+    /// Phase (0.0..1.0) of the slow [`ShimmerConfig::base_wash`] cycle, or 0.0 when `config`
+    /// has no wash or its period is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::style::{Color, Style};
+    /// use tui_shimmer::{intensity_at_with_config, ColorMode, ShimmerConfig, ShimmerState};
+    ///
+    /// let config = ShimmerConfig {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     base_wash: Some((Color::Rgb(40, 40, 120), Color::Rgb(120, 40, 40), 20.0)),
+    ///     ..ShimmerConfig::default()
+    /// };
+    /// let text = "Indexing the workspace symbols";
+    /// // Foreground of the first cell the band leaves alone.
+    /// let resting_fg = |state: &ShimmerState| {
+    ///     let phase = state.phase_for(text, &config);
+    ///     let index = (0..30)
+    ///         .find(|&index| intensity_at_with_config(index, 30, phase, &config) == 0.0)
+    ///         .unwrap();
+    ///     let spans = state.spans(text, Style::default(), &config);
+    ///     let styles: Vec<Style> =
+    ///         spans.iter().flat_map(|span| span.content.chars().map(|_| span.style)).collect();
+    ///     styles[index].fg
+    /// };
+    ///
+    /// let mut state = ShimmerState::new().ignore_time_scale();
+    /// state.tick(Duration::from_secs(1));
+    /// assert_eq!(state.wash_phase(&config), 0.05);
+    /// let early = resting_fg(&state);
+    /// state.tick(Duration::from_secs(9));
+    /// assert_eq!(state.wash_phase(&config), 0.5);
+    /// assert_eq!(resting_fg(&state), Some(Color::Rgb(120, 40, 40)));
+    /// assert_ne!(resting_fg(&state), early);
+    /// ```
+    pub fn wash_phase(&self, config: &ShimmerConfig) -> f32 {
+        match config.base_wash {
+            Some((_, _, period)) if period > 0.0 && period.is_finite() => {
+                (self.wash_elapsed / f64::from(period)).rem_euclid(1.0) as f32
+            }
+            _ => 0.0,
+        }
+    }
+}
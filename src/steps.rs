@@ -0,0 +1,192 @@
+use std::cmp::Ordering;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Modifier, Style};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::{build_span, char_width, shimmer_spans_with_config, ShimmerConfig};
+
+/// A checklist of steps in which only the current step's label shimmers.
+///
+/// Each step renders on its own row as a glyph, a space and the label. Steps before the
+/// current one are complete, the current one shimmers at the widget's
+/// [`phase`](StepList::phase), and the rest are pending. Labels are cut to the area width
+/// before shimmering, so the band sweeps over what is visible.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Modifier;
+/// use ratatui::widgets::Widget;
+/// use tui_shimmer::{ColorMode, ShimmerConfig, StepList};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let steps = StepList::new(["Resolve deps", "Compile", "Run tests"])
+///     .current(1)
+///     .phase(0.5)
+///     .config(config);
+/// let area = Rect::new(0, 0, 16, 3);
+/// let mut buf = Buffer::empty(area);
+/// steps.render(area, &mut buf);
+///
+/// assert_eq!(buf[(0, 0)].symbol(), "✓");
+/// assert_eq!(buf[(0, 1)].symbol(), "•");
+/// assert_eq!(buf[(0, 2)].symbol(), "·");
+/// assert!(buf[(2, 2)].modifier.contains(Modifier::DIM));
+/// // Only the current label is styled by the band.
+/// assert_eq!(buf[(5, 1)].symbol(), "p");
+/// assert_ne!(buf[(5, 1)].style(), buf[(2, 1)].style());
+/// assert_eq!(buf[(2, 0)].style(), buf[(3, 0)].style());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepList {
+    steps: Vec<String>,
+    current: usize,
+    phase: f32,
+    config: ShimmerConfig,
+    glyphs: [char; 3],
+    done_style: Style,
+    active_style: Style,
+    pending_style: Style,
+}
+
+impl StepList {
+    /// Creates a list of `steps` with the first one current.
+    pub fn new(steps: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            steps: steps.into_iter().map(Into::into).collect(),
+            current: 0,
+            phase: 0.0,
+            config: ShimmerConfig::default(),
+            glyphs: ['✓', '•', '·'],
+            done_style: Style::default(),
+            active_style: Style::default(),
+            pending_style: Style::default().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets the index of the current step; steps before it render as complete.
+    #[must_use]
+    pub fn current(mut self, current: usize) -> Self {
+        self.set_current(current);
+        self
+    }
+
+    /// Sets the band phase (0.0..1.0) of the current step. Defaults to 0.0.
+    #[must_use]
+    pub fn phase(mut self, phase: f32) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the shimmer configuration of the current step.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the glyphs of complete, current and pending steps. Defaults to `✓`, `•` and `·`.
+    #[must_use]
+    pub fn glyphs(mut self, done: char, active: char, pending: char) -> Self {
+        self.glyphs = [done, active, pending];
+        self
+    }
+
+    /// Sets the style of complete steps. Defaults to the plain style.
+    #[must_use]
+    pub fn done_style(mut self, style: Style) -> Self {
+        self.done_style = style;
+        self
+    }
+
+    /// Sets the base style the current step shimmers from. Defaults to the plain style.
+    #[must_use]
+    pub fn active_style(mut self, style: Style) -> Self {
+        self.active_style = style;
+        self
+    }
+
+    /// Sets the style of pending steps. Defaults to `DIM`.
+    #[must_use]
+    pub fn pending_style(mut self, style: Style) -> Self {
+        self.pending_style = style;
+        self
+    }
+
+    /// Makes the step at `current` the one that shimmers. Values past the last step mark
+    /// every step complete.
+    pub fn set_current(&mut self, current: usize) {
+        self.current = current.min(self.steps.len());
+    }
+
+    /// Marks every step complete, leaving nothing to shimmer.
+    pub fn complete_all(&mut self) {
+        self.current = self.steps.len();
+    }
+
+    /// Index of the current step, equal to the number of steps once all are complete.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Whether every step is complete.
+    pub fn is_complete(&self) -> bool {
+        self.current == self.steps.len()
+    }
+
+    /// Renders step `index`, cutting its label so the whole line fits in `width` columns.
+    fn line(&self, index: usize, width: usize) -> Line<'static> {
+        let (glyph, style) = match index.cmp(&self.current) {
+            Ordering::Less => (self.glyphs[0], self.done_style),
+            Ordering::Equal => (self.glyphs[1], self.active_style),
+            Ordering::Greater => (self.glyphs[2], self.pending_style),
+        };
+        let label_width = width.saturating_sub(char_width(glyph) + 1);
+        let label = truncate_to_width(&self.steps[index], label_width);
+        let mut spans = vec![build_span(format!("{glyph} "), style)];
+        if index == self.current {
+            spans.extend(shimmer_spans_with_config(
+                label,
+                style,
+                self.phase,
+                &self.config,
+            ));
+        } else {
+            spans.push(build_span(label, style));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Widget for StepList {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &StepList {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for (row, index) in (area.top()..area.bottom()).zip(0..self.steps.len()) {
+            let line = self.line(index, usize::from(area.width));
+            buf.set_line(area.x, row, &line, area.width);
+        }
+    }
+}
+
+/// The longest prefix of `text` that fits in `width` columns.
+fn truncate_to_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (offset, ch) in text.char_indices() {
+        used += char_width(ch);
+        if used > width {
+            return &text[..offset];
+        }
+    }
+    text
+}
@@ -0,0 +1,164 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::{moves_rtl, shimmer_spans_with_config, ColorMode, Frame, ShimmerConfig};
+
+/// Per-cell intensities for every step of one sweep, computed once up front.
+///
+/// Step `n` of `steps` holds the band at phase `n / steps`, so rendering a step only looks
+/// intensities up instead of recomputing the falloff for every cell. This pays off for long
+/// static labels rendered every frame; the table is as large as `steps * char_count` floats.
+/// The config is captured at build time and used for every render.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShadowConfig, ShimmerConfig, ShimmerTable};
+///
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     shadow: Some(ShadowConfig { offset: 4, strength: 0.3 }),
+///     leading_edge_modifier: Some(Modifier::BOLD),
+///     ..ShimmerConfig::default()
+/// };
+/// let text = "Compiling dependencies...";
+/// let base = Style::default().fg(Color::Rgb(120, 120, 120));
+/// let table = ShimmerTable::build(text.chars().count(), 40, &config);
+///
+/// assert_eq!(table.steps(), 40);
+/// for step in 0..table.steps() {
+///     let phase = step as f32 / 40.0;
+///     assert_eq!(table.spans(text, base, step), shimmer_spans_with_config(text, base, phase, &config));
+/// }
+/// // Steps wrap around, and phases map to the step at or before them.
+/// assert_eq!(table.spans(text, base, 41), table.spans(text, base, 1));
+/// assert_eq!(table.step_at(0.26), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShimmerTable {
+    char_count: usize,
+    config: ShimmerConfig,
+    steps: Vec<TableStep>,
+}
+
+/// The precomputed band for one step of a [`ShimmerTable`].
+#[derive(Debug, Clone)]
+pub(crate) struct TableStep {
+    pub(crate) intensity: Vec<f32>,
+    /// Intensities of the [`ShimmerConfig::shadow`] band; empty without a shadow.
+    pub(crate) shadow: Vec<f32>,
+    pub(crate) touches_text: bool,
+    pub(crate) leading_edge: Option<usize>,
+}
+
+impl ShimmerTable {
+    /// Precomputes `steps` evenly spaced phases for text of `char_count` characters.
+    ///
+    /// `steps` of 0 is treated as 1.
+    pub fn build(char_count: usize, steps: usize, config: &ShimmerConfig) -> Self {
+        let count = steps.max(1);
+        let steps = (0..count)
+            .map(|step| {
+                let band = Band::new(char_count, step as f32 / count as f32, config);
+                TableStep {
+                    intensity: (0..char_count).map(|index| band.intensity(index)).collect(),
+                    shadow: config
+                        .shadow
+                        .map(|shadow| {
+                            (0..char_count)
+                                .map(|index| band.intensity_offset(index, shadow.offset))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    touches_text: band.touches_text(char_count),
+                    leading_edge: band.leading_edge(moves_rtl(config)),
+                }
+            })
+            .collect();
+        Self {
+            char_count,
+            config: config.clone(),
+            steps,
+        }
+    }
+
+    /// Number of steps in one sweep.
+    pub fn steps(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Step rendered at `phase` (0.0..1.0): the last one at or before it.
+    pub fn step_at(&self, phase: f32) -> usize {
+        let step = (phase.rem_euclid(1.0) * self.steps.len() as f32) as usize;
+        step.min(self.steps.len() - 1)
+    }
+
+    /// Renders `text` at `step`, wrapping past the last step.
+    ///
+    /// Matches [`shimmer_spans_with_config`] at phase `step / steps`. Text whose length differs
+    /// from the table's `char_count` is rendered directly at that phase instead.
+    pub fn spans(&self, text: &str, base_style: Style, step: usize) -> Vec<Span<'static>> {
+        let step = step % self.steps.len();
+        let char_count = text.chars().count();
+        if char_count != self.char_count {
+            let phase = step as f32 / self.steps.len() as f32;
+            return shimmer_spans_with_config(text, base_style, phase, &self.config);
+        }
+        if char_count == 0 {
+            return Vec::new();
+        }
+        let mode = self.config.render_mode();
+        Frame::from_table(text, base_style, &self.steps[step], &self.config, mode)
+            .spans(text, char_count)
+            .0
+    }
+}
+
+/// Where a frame reads its per-cell intensities.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Intensities<'a> {
+    /// Computed from the band position on demand.
+    Band(Band),
+    /// Read from one step of a [`ShimmerTable`].
+    Table(&'a TableStep),
+}
+
+impl Intensities<'_> {
+    pub(crate) fn intensity(&self, index: usize) -> f32 {
+        match self {
+            Intensities::Band(band) => band.intensity(index),
+            Intensities::Table(step) => step.intensity[index],
+        }
+    }
+
+    /// Intensity of the [`ShimmerConfig::shadow`] band, `offset` cells behind, at `index`.
+    pub(crate) fn shadow(&self, index: usize, offset: isize) -> f32 {
+        match self {
+            Intensities::Band(band) => band.intensity_offset(index, offset),
+            Intensities::Table(step) => step.shadow[index],
+        }
+    }
+}
+
+impl<'a> Frame<'a> {
+    /// Like [`Frame::with_mode`] with the intensities read from a [`ShimmerTable`] step.
+    pub(crate) fn from_table(
+        text: &str,
+        base_style: Style,
+        step: &'a TableStep,
+        config: &'a ShimmerConfig,
+        mode: ColorMode,
+    ) -> Self {
+        Self::from_parts(
+            text,
+            base_style,
+            config,
+            mode,
+            Intensities::Table(step),
+            step.touches_text,
+            step.leading_edge,
+        )
+    }
+}
@@ -0,0 +1,237 @@
+//! Helpers for asserting on rendered shimmer output in tests.
+
+use std::fmt::Write as _;
+
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::Span;
+
+use crate::{Frame, ShimmerConfig};
+
+/// Panics unless `spans` is a valid shimmer rendering of `text`.
+///
+/// Every span builder in this crate upholds these invariants, whatever the mode or phase:
+///
+/// - the span contents concatenate to `text`, up to the case of the cells that
+///   [`ShimmerConfig::band_transform`] changes;
+/// - no span is empty;
+/// - no two adjacent spans share a style, so merging is maximal.
+///
+/// Use it in integration tests to check output that passes through your own code, and
+/// [`assert_valid_frame_with_config`] to also bound the span count by the band.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::testing::assert_valid_frame;
+/// use tui_shimmer::{shimmer_spans_with_config, BandTransform, ShimmerConfig};
+///
+/// let text = "Loading…";
+/// let spans = shimmer_spans_with_config(text, Style::default(), 0.3, &ShimmerConfig::default());
+/// assert_valid_frame(text, &spans);
+///
+/// let config = ShimmerConfig { band_transform: BandTransform::Upper, ..ShimmerConfig::default() };
+/// let spans = shimmer_spans_with_config("loading bar", Style::default(), 0.5, &config);
+/// assert_valid_frame("loading bar", &spans);
+/// ```
+#[track_caller]
+pub fn assert_valid_frame(text: &str, spans: &[Span<'_>]) {
+    let plain = spans_to_plain(spans);
+    assert!(
+        plain == text || same_ignoring_case(&plain, text),
+        "span contents do not reassemble the text: {plain:?} != {text:?}"
+    );
+    if let Some(index) = spans.iter().position(|span| span.content.is_empty()) {
+        panic!("span {index} is empty");
+    }
+    if let Some(index) = spans
+        .windows(2)
+        .position(|pair| pair[0].style == pair[1].style)
+    {
+        panic!(
+            "spans {index} and {} share a style and should be merged",
+            index + 1
+        );
+    }
+}
+
+/// Like [`assert_valid_frame`], and also panics when `spans` has more spans than the band of
+/// `config` can produce.
+///
+/// Cells the band leaves alone share one style, so a frame has at most two spans for each
+/// cell the band (and its [`ShimmerConfig::shadow`]) can reach, plus one.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::testing::assert_valid_frame_with_config;
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let text = "A long line of text the band only ever lights a small part of";
+/// for step in 0..=20 {
+///     let spans = shimmer_spans_with_config(text, Style::default(), step as f32 / 20.0, &config);
+///     assert_valid_frame_with_config(text, &spans, &config);
+/// }
+/// ```
+#[track_caller]
+pub fn assert_valid_frame_with_config(text: &str, spans: &[Span<'_>], config: &ShimmerConfig) {
+    assert_valid_frame(text, spans);
+    let bound = max_span_count(text.chars().count(), config);
+    assert!(
+        spans.len() <= bound,
+        "{} spans, but the band allows at most {bound}",
+        spans.len()
+    );
+}
+
+/// Upper bound on the spans of one frame of `char_count` chars, for
+/// [`assert_valid_frame_with_config`].
+fn max_span_count(char_count: usize, config: &ShimmerConfig) -> usize {
+    let half_width = |width: Option<usize>| {
+        width.map_or(config.band_half_width, |width| width.min(config.padding))
+    };
+    let window = half_width(config.leading_width)
+        + half_width(config.trailing_width)
+        + 1
+        + usize::from(config.sub_cell);
+    let lit = window * if config.shadow.is_some() { 2 } else { 1 };
+    (2 * lit.min(char_count) + 1).min(char_count.max(1))
+}
+
+/// Whether `a` and `b` differ only in the case of single chars, as
+/// [`ShimmerConfig::band_transform`] changes them.
+fn same_ignoring_case(a: &str, b: &str) -> bool {
+    a.chars().count() == b.chars().count()
+        && a.chars().zip(b.chars()).all(|(a, b)| {
+            a == b || a.to_lowercase().eq(b.to_lowercase()) || a.to_uppercase().eq(b.to_uppercase())
+        })
+}
+
+/// Returns the style of every char of `text`, before equal neighbors are merged into spans.
+///
+/// This is the most granular view of a render and matches the styles of
+/// [`shimmer_spans_with_config`](crate::shimmer_spans_with_config) char for char, which makes
+/// it a natural basis for golden tests. Set an explicit [`ColorMode`](crate::ColorMode) in
+/// `config` so the result does not depend on the environment.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use tui_shimmer::testing::shimmer_styles;
+/// use tui_shimmer::{ColorMode, ShimmerConfig};
+///
+/// let config = ShimmerConfig {
+///     color_mode: ColorMode::TrueColor,
+///     highlight: Some(Color::Rgb(200, 200, 200)),
+///     ..ShimmerConfig::default()
+/// };
+/// let base = Style::default().fg(Color::Rgb(0, 0, 0));
+/// // The band centers on the middle char; its neighbors are one cell out at 0.905.
+/// let styles = shimmer_styles("abc", base, 0.5, &config);
+/// let cell = |level| base.fg(Color::Rgb(level, level, level)).add_modifier(Modifier::BOLD);
+/// assert_eq!(styles, [cell(163), cell(180), cell(163)]);
+/// ```
+pub fn shimmer_styles(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &ShimmerConfig,
+) -> Vec<Style> {
+    let char_count = text.chars().count();
+    if char_count == 0 {
+        return Vec::new();
+    }
+    let frame = Frame::new(text, char_count, base_style, phase, config);
+    (0..char_count).map(|index| frame.style(index)).collect()
+}
+
+/// Concatenates the contents of `spans`, dropping their styles.
+///
+/// For any span builder in this crate this returns the input text.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Style;
+/// use tui_shimmer::testing::spans_to_plain;
+/// use tui_shimmer::{shimmer_spans_with_config, ShimmerConfig};
+///
+/// let spans = shimmer_spans_with_config("Loading…", Style::default(), 0.3, &ShimmerConfig::default());
+/// assert_eq!(spans_to_plain(&spans), "Loading…");
+/// ```
+pub fn spans_to_plain(spans: &[Span<'_>]) -> String {
+    spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+/// Renders `spans` as one line of `[content]{style}` pairs, for readable assertions and diffs.
+///
+/// Each span's content is bracketed, followed by its style in braces unless the style is
+/// empty: `fg=` and `bg=` with RGB colors as `#rrggbb`, indexed colors as `i<index>` and named
+/// colors by name, then `+MODIFIER` for each added and `-MODIFIER` for each removed modifier.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Modifier, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::testing::spans_to_debug;
+/// use tui_shimmer::{shimmer_spans_with_config, ColorMode, ShimmerConfig};
+///
+/// let spans = [
+///     Span::raw("Lo"),
+///     Span::styled("ad", Style::default().fg(Color::Rgb(255, 128, 0)).add_modifier(Modifier::BOLD)),
+///     Span::styled("ing", Style::default().bg(Color::Indexed(236)).remove_modifier(Modifier::DIM)),
+/// ];
+/// assert_eq!(spans_to_debug(&spans), "[Lo][ad]{fg=#ff8000 +BOLD}[ing]{bg=i236 -DIM}");
+///
+/// // One bracketed group per span, so style boundaries are visible at a glance.
+/// let config = ShimmerConfig { color_mode: ColorMode::Basic16, ..ShimmerConfig::default() };
+/// let spans = shimmer_spans_with_config("Loading...", Style::default(), 0.5, &config);
+/// assert_eq!(spans_to_debug(&spans).matches('[').count(), spans.len());
+/// ```
+pub fn spans_to_debug(spans: &[Span<'_>]) -> String {
+    let mut debug = String::new();
+    for span in spans {
+        let _ = write!(debug, "[{}]", span.content);
+        let style = style_annotation(span.style);
+        if !style.is_empty() {
+            let _ = write!(debug, "{{{style}}}");
+        }
+    }
+    debug
+}
+
+/// The space-separated parts of a [`spans_to_debug`] style annotation.
+fn style_annotation(style: Style) -> String {
+    let mut parts = Vec::new();
+    if let Some(fg) = style.fg {
+        parts.push(format!("fg={}", color_annotation(fg)));
+    }
+    if let Some(bg) = style.bg {
+        parts.push(format!("bg={}", color_annotation(bg)));
+    }
+    parts.extend(
+        style
+            .add_modifier
+            .iter_names()
+            .map(|(name, _)| format!("+{name}")),
+    );
+    parts.extend(
+        style
+            .sub_modifier
+            .iter_names()
+            .map(|(name, _)| format!("-{name}")),
+    );
+    parts.join(" ")
+}
+
+fn color_annotation(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(index) => format!("i{index}"),
+        named => format!("{named:?}"),
+    }
+}
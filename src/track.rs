@@ -0,0 +1,93 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::band::Band;
+use crate::color::CellPainter;
+use crate::{build_span, char_width, coalesce_spans, ColorMode, ShimmerConfig};
+
+/// One band shared by several widgets laid out side by side.
+///
+/// Each widget renders its own text with [`spans_for`](BandTrack::spans_for), passing the
+/// screen column where it starts relative to the track. Cells are lit against the band over
+/// the whole `total_width`, so the band crosses from one widget into the next as if they were
+/// a single line. Segments may overlap or extend past the track; their cells are still lit by
+/// column.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::{Color, Style};
+/// use ratatui::text::Span;
+/// use tui_shimmer::{shimmer_spans_with_config, BandTrack, ColorMode, ShimmerConfig};
+///
+/// fn cells(spans: &[Span]) -> Vec<(char, Style)> {
+///     spans.iter().flat_map(|span| span.content.chars().map(|ch| (ch, span.style))).collect()
+/// }
+///
+/// let config = ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() };
+/// let base = Style::default().fg(Color::Rgb(110, 110, 110));
+/// let segments = ["Project: ", "tui-shimmer ", "(main)"];
+/// let track = BandTrack::new(27).config(config.clone());
+///
+/// // Three segments rendered at their own offsets reassemble into a single-line render.
+/// for phase in [0.2, 0.45, 0.6, 0.8] {
+///     let mut offset = 0;
+///     let mut row = Vec::new();
+///     for segment in segments {
+///         row.extend(cells(&track.spans_for(segment, base, offset, phase)));
+///         offset += segment.len();
+///     }
+///     let line = cells(&shimmer_spans_with_config(&segments.concat(), base, phase, &config));
+///     assert_eq!(row, line);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandTrack {
+    total_width: usize,
+    config: ShimmerConfig,
+}
+
+impl BandTrack {
+    /// Creates a track spanning `total_width` screen columns.
+    pub fn new(total_width: usize) -> Self {
+        Self {
+            total_width,
+            config: ShimmerConfig::default(),
+        }
+    }
+
+    /// Sets the shimmer configuration shared by every segment.
+    #[must_use]
+    pub fn config(mut self, config: ShimmerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Renders the segment `text` that starts `offset` columns into the track.
+    ///
+    /// [`ShimmerConfig::min_animated_len`] applies to the whole track, not to each segment.
+    pub fn spans_for(
+        &self,
+        text: &str,
+        base_style: Style,
+        offset: usize,
+        phase: f32,
+    ) -> Vec<Span<'static>> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+        let painter = CellPainter::new(base_style, &self.config);
+        if painter.mode() == ColorMode::Plain || self.total_width < self.config.min_animated_len {
+            return vec![build_span(text, base_style)];
+        }
+
+        let band = Band::new(self.total_width, phase, &self.config);
+        let mut column = offset;
+        let cells = text.chars().map(|ch| {
+            let cell = painter.style(column, band.intensity(column));
+            column += char_width(ch);
+            (ch, cell)
+        });
+        coalesce_spans(cells, text.len())
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::Span;
+
+use crate::color::CellPainter;
+use crate::{build_span, coalesce_spans, is_animated, ShimmerConfig};
+
+/// Settings for [`shimmer_wave_spans`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveConfig {
+    /// Distance in cells between two brightness crests. Defaults to 8.0.
+    pub wavelength_cells: f32,
+    /// Time for one full pulse of a cell, used by [`WaveConfig::phase_at`]. Defaults to 1.5 s.
+    pub period: Duration,
+    /// Peak intensity of the wave (0.0..=1.0). Defaults to 1.0.
+    pub amplitude: f32,
+    /// Number of intensity steps cells snap to, so neighbours share styles and spans merge.
+    /// 0 disables quantization. Defaults to 8.
+    pub levels: u16,
+    /// Color mode, highlight and modifiers used to style each cell.
+    pub shimmer: ShimmerConfig,
+}
+
+impl Default for WaveConfig {
+    fn default() -> Self {
+        Self {
+            wavelength_cells: 8.0,
+            period: Duration::from_millis(1500),
+            amplitude: 1.0,
+            levels: 8,
+            shimmer: ShimmerConfig::default(),
+        }
+    }
+}
+
+impl WaveConfig {
+    /// Phase (0.0..1.0) of the wave after `elapsed`, based on [`WaveConfig::period`].
+    pub fn phase_at(&self, elapsed: Duration) -> f32 {
+        if self.period.is_zero() {
+            return 0.0;
+        }
+        (elapsed.as_secs_f64() / self.period.as_secs_f64()).rem_euclid(1.0) as f32
+    }
+
+    fn intensity(&self, index: usize, phase: f32) -> f32 {
+        let offset = if self.wavelength_cells > 0.0 {
+            index as f32 / self.wavelength_cells
+        } else {
+            0.0
+        };
+        let wave = 0.5 * (1.0 + (std::f32::consts::TAU * (phase - offset)).sin());
+        let intensity = (wave * self.amplitude).clamp(0.0, 1.0);
+        if self.levels == 0 {
+            intensity
+        } else {
+            let levels = f32::from(self.levels);
+            (intensity * levels).round() / levels
+        }
+    }
+}
+
+/// Renders `text` as a travelling wave where every character pulses on its own cycle.
+///
+/// Unlike the sweeping band, the whole string is always partly lit: each cell's intensity is
+/// `0.5 * (1 + sin(2π * (phase - index / wavelength_cells))) * amplitude`, quantized to
+/// [`WaveConfig::levels`] steps, then styled like a band cell of the same intensity.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use ratatui::style::{Color, Style};
+/// use tui_shimmer::{shimmer_wave_spans, ColorMode, ShimmerConfig, WaveConfig};
+///
+/// let base = Style::default().fg(Color::Rgb(60, 60, 60));
+/// let styles = |config: &WaveConfig| -> Vec<Style> {
+///     shimmer_wave_spans("Thinking about it, please wait...", base, 0.1, config)
+///         .iter()
+///         .flat_map(|span| span.content.chars().map(|_| span.style))
+///         .collect()
+/// };
+/// let config = WaveConfig {
+///     shimmer: ShimmerConfig { color_mode: ColorMode::TrueColor, ..ShimmerConfig::default() },
+///     ..WaveConfig::default()
+/// };
+///
+/// // The brightness pattern repeats every `wavelength_cells` cells, and not sooner.
+/// let cells = styles(&config);
+/// assert!((0..cells.len() - 8).all(|index| cells[index] == cells[index + 8]));
+/// assert!((0..cells.len() - 4).any(|index| cells[index] != cells[index + 4]));
+///
+/// // Quantization snaps cells to `levels` steps above the base: at most 5 styles for 4 levels.
+/// let distinct = |config: &WaveConfig| styles(config).into_iter().collect::<HashSet<_>>().len();
+/// assert!(distinct(&WaveConfig { levels: 4, ..config.clone() }) <= 5);
+/// assert!(distinct(&WaveConfig { levels: 0, wavelength_cells: 32.0, ..config }) > 5);
+/// ```
+pub fn shimmer_wave_spans(
+    text: &str,
+    base_style: Style,
+    phase: f32,
+    config: &WaveConfig,
+) -> Vec<Span<'static>> {
+    let char_count = text.chars().count();
+    if char_count == 0 {
+        return Vec::new();
+    }
+    if !is_animated(text, &config.shimmer) {
+        return vec![build_span(text, base_style)];
+    }
+
+    let painter = CellPainter::new(base_style, &config.shimmer);
+    let phase = phase.rem_euclid(1.0);
+    let cells = text
+        .chars()
+        .enumerate()
+        .map(|(index, ch)| (ch, painter.style(index, config.intensity(index, phase))));
+    coalesce_spans(cells, char_count)
+}
@@ -0,0 +1,292 @@
+//! Property tests for the invariants of `tui_shimmer::testing::assert_valid_frame`.
+
+use std::borrow::Cow;
+
+use proptest::prelude::*;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use tui_shimmer::testing::{assert_valid_frame_with_config, shimmer_styles};
+use tui_shimmer::{
+    intensity_at, intensity_at_with_config, shimmer_display_width, shimmer_spans_with_config,
+    shimmer_spans_with_width, BandTransform, ColorMode, EffectKind, ShadowConfig, ShimmerConfig,
+    ShimmerGradient, ShimmerTable,
+};
+
+/// Text mixing ASCII, wide, combining, right-to-left and whitespace chars with arbitrary ones.
+fn text() -> impl Strategy<Value = String> {
+    let ch = prop_oneof![
+        4 => proptest::char::range('a', 'z'),
+        1 => Just(' '),
+        1 => Just('ß'),
+        1 => Just('世'),
+        1 => Just('\u{301}'),
+        1 => Just('ש'),
+        1 => Just('⏳'),
+        2 => any::<char>(),
+    ];
+    proptest::collection::vec(ch, 0..48).prop_map(|chars| chars.into_iter().collect())
+}
+
+fn phase() -> impl Strategy<Value = f32> {
+    prop_oneof![Just(0.0), Just(0.5), Just(1.0), -2.0f32..3.0]
+}
+
+fn color() -> impl Strategy<Value = Color> {
+    prop_oneof![
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Color::Rgb(r, g, b)),
+        any::<u8>().prop_map(Color::Indexed),
+        Just(Color::Cyan),
+        Just(Color::Reset),
+    ]
+}
+
+fn base_style() -> impl Strategy<Value = Style> {
+    prop_oneof![
+        Just(Style::default()),
+        color().prop_map(|fg| Style::default().fg(fg)),
+        (color(), color()).prop_map(|(fg, bg)| Style::default().fg(fg).bg(bg)),
+    ]
+}
+
+fn color_mode() -> impl Strategy<Value = ColorMode> {
+    prop_oneof![
+        Just(ColorMode::TrueColor),
+        Just(ColorMode::Indexed256),
+        Just(ColorMode::Basic16),
+        Just(ColorMode::ModifierOnly),
+        Just(ColorMode::Plain),
+        Just(ColorMode::PeakModifier(Modifier::REVERSED)),
+    ]
+}
+
+/// The band's shape: half-widths, padding, direction and sub-cell positioning.
+fn geometry() -> impl Strategy<Value = ShimmerConfig> {
+    (
+        0usize..8,
+        proptest::option::of(0usize..8),
+        proptest::option::of(0usize..8),
+        0usize..14,
+        proptest::option::of(prop_oneof![-40.0f32..-1.0, 1.0f32..40.0]),
+        any::<bool>(),
+        proptest::option::of((0usize..40, 0usize..40).prop_map(|(start, end)| start..end)),
+        0usize..5,
+    )
+        .prop_map(
+            |(
+                band_half_width,
+                leading_width,
+                trailing_width,
+                padding,
+                velocity,
+                sub_cell,
+                active_range,
+                min_animated_len,
+            )| ShimmerConfig {
+                band_half_width,
+                leading_width,
+                trailing_width,
+                padding,
+                velocity,
+                sub_cell,
+                active_range,
+                min_animated_len,
+                ..ShimmerConfig::default()
+            },
+        )
+}
+
+/// A config across every color mode, direction and styling option.
+fn config() -> impl Strategy<Value = ShimmerConfig> {
+    let shadow = proptest::option::of((-6isize..6, 0.0f32..1.0))
+        .prop_map(|shadow| shadow.map(|(offset, strength)| ShadowConfig { offset, strength }));
+    let gradient = proptest::option::of((color(), color())).prop_map(|stops| {
+        stops.map(|(from, to)| ShimmerGradient::new(vec![(0.0, from), (1.0, to)]).unwrap())
+    });
+    let transform = prop_oneof![
+        Just(BandTransform::None),
+        Just(BandTransform::Upper),
+        Just(BandTransform::Lower),
+    ];
+    let effect = prop_oneof![Just(EffectKind::Highlight), Just(EffectKind::ShadowSweep)];
+    (
+        geometry(),
+        color_mode(),
+        (any::<bool>(), any::<bool>(), any::<bool>()),
+        proptest::option::of(color()),
+        gradient,
+        shadow,
+        (transform, effect),
+        (0u8..8, 0.0f32..1.0, 0.0f32..0.5),
+        proptest::option::of(Just(Modifier::UNDERLINED)),
+        proptest::option::of((color(), color(), 1.0f32..10.0)),
+    )
+        .prop_map(
+            |(
+                geometry,
+                color_mode,
+                (dither, plain_outside_band, plain_whitespace),
+                highlight,
+                gradient,
+                shadow,
+                (band_transform, effect),
+                (color_quantization, center_emphasis, dead_zone),
+                leading_edge_modifier,
+                base_wash,
+            )| ShimmerConfig {
+                color_mode,
+                dither,
+                plain_outside_band,
+                plain_whitespace,
+                highlight,
+                gradient,
+                shadow,
+                band_transform,
+                effect,
+                color_quantization,
+                center_emphasis,
+                dead_zone,
+                leading_edge_modifier,
+                base_wash,
+                ignore_shimmer_enabled: true,
+                ..geometry
+            },
+        )
+}
+
+proptest! {
+    #[test]
+    fn spans_with_config_are_valid(
+        text in text(),
+        base_style in base_style(),
+        phase in phase(),
+        config in config(),
+    ) {
+        let spans = shimmer_spans_with_config(&text, base_style, phase, &config);
+        assert_valid_frame_with_config(&text, &spans, &config);
+    }
+
+    #[test]
+    fn spans_match_span_styled(
+        text in text(),
+        base_style in base_style(),
+        phase in phase(),
+        config in config(),
+    ) {
+        // Every span goes through the crate's `build_span`, which must build exactly what
+        // `Span::styled` builds from an owned string on the supported ratatui-core version.
+        for span in shimmer_spans_with_config(&text, base_style, phase, &config) {
+            prop_assert!(matches!(span.content, Cow::Owned(_)));
+            prop_assert_eq!(&span, &Span::styled(span.content.to_string(), span.style));
+        }
+    }
+
+    #[test]
+    fn width_matches_display_width(
+        text in text(),
+        base_style in base_style(),
+        phase in phase(),
+        config in config(),
+    ) {
+        let (spans, width) = shimmer_spans_with_width(&text, base_style, phase, &config);
+        prop_assert_eq!(width, shimmer_display_width(&text));
+        prop_assert_eq!(spans, shimmer_spans_with_config(&text, base_style, phase, &config));
+    }
+
+    #[test]
+    fn band_transform_changes_only_case(
+        text in text(),
+        phase in phase(),
+        config in config(),
+    ) {
+        let plain = ShimmerConfig { band_transform: BandTransform::None, ..config.clone() };
+        let transformed = shimmer_spans_with_config(&text, Style::default(), phase, &config);
+        let untransformed = shimmer_spans_with_config(&text, Style::default(), phase, &plain);
+        let styles = |spans: &[Span]| {
+            spans
+                .iter()
+                .flat_map(|span| span.content.chars().map(|_| span.style))
+                .collect::<Vec<_>>()
+        };
+        prop_assert_eq!(styles(&transformed), styles(&untransformed));
+    }
+
+    #[test]
+    fn table_spans_are_valid(
+        text in text(),
+        base_style in base_style(),
+        steps in 1usize..24,
+        step in 0usize..48,
+        config in config(),
+    ) {
+        let table = ShimmerTable::build(text.chars().count(), steps, &config);
+        let spans = table.spans(&text, base_style, step);
+        assert_valid_frame_with_config(&text, &spans, &config);
+    }
+
+    #[test]
+    fn intensity_follows_the_band(
+        char_count in 0usize..60,
+        phase in 0.0f32..1.0,
+        half_width in 0usize..8,
+        padding in 8usize..14,
+    ) {
+        let config = ShimmerConfig {
+            color_mode: ColorMode::TrueColor,
+            band_half_width: half_width,
+            padding,
+            highlight: Some(Color::Rgb(255, 255, 255)),
+            ..ShimmerConfig::default()
+        };
+        // The band center from the documented formula, relative to the first cell.
+        let period = char_count + 2 * padding;
+        let center = (phase * period as f32) as isize - padding as isize;
+        let intensity = |index: isize| intensity_at_with_config(index as usize, char_count, phase, &config);
+        let cells = 0..char_count as isize;
+
+        for index in cells.clone() {
+            let dist = (index - center).unsigned_abs();
+            if dist > half_width {
+                prop_assert_eq!(intensity(index), 0.0);
+            }
+            // Symmetric around the peak wherever both sides fall on the text.
+            let mirror = 2 * center - index;
+            if cells.contains(&mirror) {
+                prop_assert_eq!(intensity(index), intensity(mirror));
+            }
+            // The maximum is at the peak cell alone.
+            if index != center && cells.contains(&center) {
+                prop_assert!(intensity(index) < intensity(center));
+            }
+        }
+        if cells.contains(&center) {
+            prop_assert_eq!(intensity(center), 1.0);
+        }
+
+        // The span builders color each cell by exactly this intensity: resting cells keep the
+        // resting style and a brighter cell never gets a darker color.
+        let base = Style::default().fg(Color::Rgb(0, 0, 0));
+        let text = "x".repeat(char_count);
+        let styles = shimmer_styles(&text, base, phase, &config);
+        let resting = shimmer_styles(&text, base, 0.0, &config).first().copied();
+        let level = |style: &Style| match style.fg {
+            Some(Color::Rgb(level, _, _)) => level,
+            other => panic!("expected an RGB foreground, got {other:?}"),
+        };
+        for (index, style) in styles.iter().enumerate() {
+            let value = intensity(index as isize);
+            if value == 0.0 && char_count >= config.min_animated_len {
+                prop_assert_eq!(Some(*style), resting);
+            }
+            for (other, other_style) in styles.iter().enumerate() {
+                if value > intensity(other as isize) {
+                    prop_assert!(level(style) >= level(other_style));
+                }
+            }
+        }
+        if half_width == 5 && padding == 10 {
+            for index in cells {
+                prop_assert_eq!(intensity(index), intensity_at(index as usize, char_count, phase));
+            }
+        }
+    }
+}